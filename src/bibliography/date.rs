@@ -0,0 +1,273 @@
+//! Dates in the Extended Date/Time Format (EDTF), levels 0 and 1.
+//!
+//! Reference dates such as `issued` are given as EDTF strings: calendar dates ("2020",
+//! "2020-05", "2020-05-17", optionally with a time), seasons ("2020-21" for spring 2020),
+//! uncertain ("2020?"), approximate ("2020~") or both ("2020%") dates, years with unspecified
+//! digits ("201X"), years of more than four digits ("Y-170000") and intervals, closed
+//! ("2019/2021") or open ("2019/..", "/2021").
+//!
+//! Years are numbered astronomically, as in EDTF: year 0 is 1 BCE and year -44 is 45 BCE.
+
+use std::fmt;
+use std::str::FromStr;
+
+/// A date or interval parsed from an EDTF string.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Edtf {
+    Date(Date),
+    Interval(Bound, Bound),
+}
+
+/// One end of an interval.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Bound {
+    Date(Date),
+    /// The interval is open at this end ("..").
+    Open,
+    /// This end of the interval is unknown (empty).
+    Unknown,
+}
+
+/// A single date, as precise as given.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Date {
+    /// The astronomical year.
+    pub year: i64,
+    /// The month, from 1 to 12.
+    pub month: Option<u8>,
+    /// The season, instead of a month.
+    pub season: Option<Season>,
+    /// The day of the month.
+    pub day: Option<u8>,
+    pub time: Option<Time>,
+    /// The number of trailing digits of the year left unspecified, as in "201X".
+    pub unspecified_digits: u8,
+    /// Whether the date is marked uncertain ("?" or "%").
+    pub uncertain: bool,
+    /// Whether the date is marked approximate ("~" or "%").
+    pub approximate: bool,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Season {
+    Spring,
+    Summer,
+    Autumn,
+    Winter,
+}
+
+/// A time of day, with an offset from UTC in minutes when one is given.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Time {
+    pub hour: u8,
+    pub minute: u8,
+    pub second: u8,
+    pub offset: Option<i16>,
+}
+
+/// The error raised for a string that is not a level 0 or 1 EDTF date.
+#[derive(Debug, PartialEq)]
+pub struct EdtfError(String);
+
+impl fmt::Display for EdtfError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "\"{}\" is not an EDTF date", self.0)
+    }
+}
+
+impl std::error::Error for EdtfError {}
+
+impl Edtf {
+    /// The first date: the date itself, or the start of an interval, or else its end.
+    pub fn first(&self) -> Option<&Date> {
+        match self {
+            Edtf::Date(date) => Some(date),
+            Edtf::Interval(start, end) => start.date().or(end.date()),
+        }
+    }
+
+    /// The year of the first date.
+    pub fn year(&self) -> Option<i64> {
+        self.first().map(|date| date.year)
+    }
+}
+
+impl Bound {
+    pub fn date(&self) -> Option<&Date> {
+        match self {
+            Bound::Date(date) => Some(date),
+            Bound::Open | Bound::Unknown => None,
+        }
+    }
+}
+
+impl FromStr for Edtf {
+    type Err = EdtfError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let error = || EdtfError(value.to_string());
+        match value.split_once('/') {
+            None => parse_date(value).map(Edtf::Date).ok_or_else(error),
+            Some((start, end)) => {
+                let start = parse_bound(start).ok_or_else(error)?;
+                let end = parse_bound(end).ok_or_else(error)?;
+                match (&start, &end) {
+                    (Bound::Date(_), _) | (_, Bound::Date(_)) => Ok(Edtf::Interval(start, end)),
+                    _ => Err(error()),
+                }
+            }
+        }
+    }
+}
+
+fn parse_bound(value: &str) -> Option<Bound> {
+    match value {
+        "" => Some(Bound::Unknown),
+        ".." => Some(Bound::Open),
+        _ => parse_date(value).map(Bound::Date),
+    }
+}
+
+fn parse_date(value: &str) -> Option<Date> {
+    let (value, uncertain, approximate) = match value.chars().last()? {
+        '?' => (&value[..value.len() - 1], true, false),
+        '~' => (&value[..value.len() - 1], false, true),
+        '%' => (&value[..value.len() - 1], true, true),
+        _ => (value, false, false),
+    };
+    let (value, time) = match value.split_once('T') {
+        Some((date, time)) => (date, Some(parse_time(time)?)),
+        None => (value, None),
+    };
+
+    let (year, rest) = match value.strip_prefix('Y') {
+        Some(value) => split_year(value, 5..)?,
+        None => split_year(value, 4..5)?,
+    };
+    let mut date = Date {
+        uncertain,
+        approximate,
+        time,
+        ..Date::default()
+    };
+    let digits = year.trim_start_matches('-');
+    date.unspecified_digits = digits.chars().rev().take_while(|c| *c == 'X').count() as u8;
+    let specified = &digits[..digits.len() - date.unspecified_digits as usize];
+    if !specified.chars().all(|c| c.is_ascii_digit()) || date.unspecified_digits > 2 {
+        return None;
+    }
+    let magnitude: i64 = format!("{:0<width$}", specified, width = digits.len())
+        .parse()
+        .ok()?;
+    date.year = if year.starts_with('-') {
+        -magnitude
+    } else {
+        magnitude
+    };
+
+    let mut parts = rest.split('-').filter(|_| !rest.is_empty());
+    match parts.next() {
+        None => {}
+        Some("XX") => {}
+        Some(month) => match two_digits(month)? {
+            month @ 1..=12 => date.month = Some(month),
+            21 => date.season = Some(Season::Spring),
+            22 => date.season = Some(Season::Summer),
+            23 => date.season = Some(Season::Autumn),
+            24 => date.season = Some(Season::Winter),
+            _ => return None,
+        },
+    }
+    match parts.next() {
+        None => {}
+        Some(_) if date.season.is_some() => return None,
+        Some("XX") => {}
+        Some(_) if date.month.is_none() => return None,
+        Some(day) => {
+            let day = two_digits(day)?;
+            if day == 0 || day > days_in_month(date.year, date.month?) {
+                return None;
+            }
+            date.day = Some(day);
+        }
+    }
+    if parts.next().is_some() || (date.time.is_some() && date.day.is_none()) {
+        return None;
+    }
+    if date.unspecified_digits > 0 && (date.month.is_some() || date.season.is_some()) {
+        return None;
+    }
+    Some(date)
+}
+
+/// Split a date into its year, of a number of digits in the range, and the rest after a "-".
+fn split_year(value: &str, digits: impl std::ops::RangeBounds<usize>) -> Option<(&str, &str)> {
+    let sign = usize::from(value.starts_with('-'));
+    let end = value[sign..]
+        .find('-')
+        .map(|index| index + sign)
+        .unwrap_or(value.len());
+    if !digits.contains(&(end - sign)) {
+        return None;
+    }
+    let rest = value.get(end + 1..).unwrap_or_default();
+    if end < value.len() && rest.is_empty() {
+        return None;
+    }
+    Some((&value[..end], rest))
+}
+
+fn parse_time(value: &str) -> Option<Time> {
+    let (value, offset) = if let Some(value) = value.strip_suffix('Z') {
+        (value, Some(0))
+    } else if let Some(index) = value.rfind(['+', '-']) {
+        let sign = if value[index..].starts_with('-') {
+            -1
+        } else {
+            1
+        };
+        let (hours, minutes) = match value[index + 1..].split_once(':') {
+            Some((hours, minutes)) => (two_digits(hours)?, two_digits(minutes)?),
+            None => (two_digits(&value[index + 1..])?, 0),
+        };
+        if hours > 14 || minutes > 59 {
+            return None;
+        }
+        (
+            &value[..index],
+            Some(sign * (i16::from(hours) * 60 + i16::from(minutes))),
+        )
+    } else {
+        (value, None)
+    };
+    let mut parts = value.split(':');
+    let hour = two_digits(parts.next()?)?;
+    let minute = two_digits(parts.next()?)?;
+    let second = two_digits(parts.next()?)?;
+    if parts.next().is_some() || hour > 23 || minute > 59 || second > 59 {
+        return None;
+    }
+    Some(Time {
+        hour,
+        minute,
+        second,
+        offset,
+    })
+}
+
+fn two_digits(value: &str) -> Option<u8> {
+    if value.len() == 2 && value.chars().all(|c| c.is_ascii_digit()) {
+        value.parse().ok()
+    } else {
+        None
+    }
+}
+
+fn days_in_month(year: i64, month: u8) -> u8 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
@@ -1,7 +1,9 @@
 use serde::{Serialize, Deserialize};
 use std::collections::HashMap;
 
-mod reference;
-use reference::InputReference;
+pub mod date;
+pub mod name;
+pub mod reference;
+pub use reference::InputReference;
 
 pub type InputBibliography = HashMap<String, InputReference>;
@@ -0,0 +1,171 @@
+//! Structured personal names, and the parsing of names given as a single string.
+//!
+//! A name string is either in sort order, with the family name before a comma ("van der
+//! Berg, Jan Jr."), or in display order, with the family name last ("Jan van der Berg Jr.").
+//! Lowercase words before the family name are particles kept with it ("van der"), and
+//! lowercase words after the given names in sort order are particles dropped from the sort
+//! form ("Beethoven, Ludwig van"). Suffixes such as "Jr." or "III" follow the given names,
+//! or the name after a comma.
+
+use crate::bibliography::reference::Contributor;
+
+/// A name in parts, or a literal name such as that of an organisation.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Name {
+    pub family: Option<String>,
+    pub given: Option<String>,
+    /// A particle kept with the family name in every form, as "van der" in "van der Berg".
+    pub non_dropping_particle: Option<String>,
+    /// A particle rendered only with the given names, as "van" in "Ludwig van Beethoven".
+    pub dropping_particle: Option<String>,
+    pub suffix: Option<String>,
+    /// A name rendered as is.
+    pub literal: Option<String>,
+}
+
+/// The suffixes recognized at the end of a name string.
+const SUFFIXES: &[&str] = &["Jr.", "Jr", "Sr.", "Sr", "II", "III", "IV"];
+
+impl Name {
+    /// A literal name.
+    pub fn literal(value: &str) -> Name {
+        Name {
+            literal: Some(value.trim().to_string()),
+            ..Name::default()
+        }
+    }
+
+    /// Parse a name string in sort or display order into its parts. Empty parts between
+    /// commas are ignored.
+    pub fn parse(value: &str) -> Name {
+        let parts: Vec<&str> = value
+            .split(',')
+            .map(str::trim)
+            .filter(|part| !part.is_empty())
+            .collect();
+        match parts.as_slice() {
+            [display] => parse_display(display),
+            [display, suffix] if is_suffix(suffix) => Name {
+                suffix: Some(suffix.to_string()),
+                ..parse_display(display)
+            },
+            [family, given] => parse_sort(family, given, None),
+            [family, given, suffix @ ..] => parse_sort(family, given, Some(&suffix.join(", "))),
+            [] => Name::default(),
+        }
+    }
+
+    /// The family name with its non-dropping particle: "van der Berg".
+    pub fn family_part(&self) -> Option<String> {
+        let family = self.family.as_deref()?;
+        Some(match self.non_dropping_particle.as_deref() {
+            Some(particle) if particle.ends_with(['\'', '’', '-']) => {
+                format!("{}{}", particle, family)
+            }
+            Some(particle) => format!("{} {}", particle, family),
+            None => family.to_string(),
+        })
+    }
+}
+
+impl Contributor {
+    /// The name of the contributor in parts.
+    ///
+    /// The parts are taken from the structured fields when any is given. A `name` string is
+    /// parsed unless `parse` is false, in which case it is a literal name.
+    pub fn structured_name(&self) -> Name {
+        if let Some(literal) = &self.literal {
+            return Name::literal(literal);
+        }
+        if self.family.is_some() || self.given.is_some() {
+            return Name {
+                family: self.family.clone(),
+                given: self.given.clone(),
+                non_dropping_particle: self.non_dropping_particle.clone(),
+                dropping_particle: self.dropping_particle.clone(),
+                suffix: self.suffix.clone(),
+                literal: None,
+            };
+        }
+        match (&self.name, self.parse) {
+            (Some(name), Some(false)) => Name::literal(name),
+            (Some(name), _) => Name::parse(name),
+            (None, _) => Name::default(),
+        }
+    }
+}
+
+/// Parse a name in display order: given names, particles, family name, suffix. A name
+/// starting with a particle, such as "van Berg", has no given names.
+fn parse_display(value: &str) -> Name {
+    let mut words: Vec<&str> = value.split_whitespace().collect();
+    let suffix = take_suffix(&mut words);
+    if words.is_empty() {
+        return Name::default();
+    }
+    let particles = words
+        .iter()
+        .position(|word| is_particle(word))
+        .and_then(|start| {
+            let end = start + words[start..].iter().take_while(|w| is_particle(w)).count();
+            (end < words.len()).then_some((start, end))
+        });
+    let (given, particle, family) = match particles {
+        Some((start, end)) => (&words[..start], &words[start..end], &words[end..]),
+        None => {
+            let (family, given) = words.split_last().unwrap();
+            (given, &[][..], std::slice::from_ref(family))
+        }
+    };
+    Name {
+        family: join(family),
+        given: join(given),
+        non_dropping_particle: join(particle),
+        suffix,
+        ..Name::default()
+    }
+}
+
+/// Parse a name in sort order: particles and family name, then given names, particles and
+/// suffix.
+fn parse_sort(family: &str, given: &str, suffix: Option<&str>) -> Name {
+    let family: Vec<&str> = family.split_whitespace().collect();
+    let particles = family.iter().take_while(|word| is_particle(word)).count();
+    let particles = particles.min(family.len().saturating_sub(1));
+    let mut given: Vec<&str> = given.split_whitespace().collect();
+    let suffix = match suffix {
+        Some(suffix) => Some(suffix.to_string()),
+        None => take_suffix(&mut given),
+    };
+    let names = given.len() - given.iter().rev().take_while(|w| is_particle(w)).count();
+    Name {
+        family: join(&family[particles..]),
+        given: join(&given[..names]),
+        non_dropping_particle: join(&family[..particles]),
+        dropping_particle: join(&given[names..]),
+        suffix,
+        literal: None,
+    }
+}
+
+/// Remove a suffix from the end of the words of a name, unless it is the only word.
+fn take_suffix(words: &mut Vec<&str>) -> Option<String> {
+    match words.last() {
+        Some(last) if words.len() > 1 && is_suffix(last) => words.pop().map(str::to_string),
+        _ => None,
+    }
+}
+
+fn is_suffix(word: &str) -> bool {
+    SUFFIXES.contains(&word)
+}
+
+/// Whether a word is a particle, such as "van", "de" or "d'": it starts with a lowercase
+/// letter.
+fn is_particle(word: &str) -> bool {
+    word.chars().next().is_some_and(char::is_lowercase)
+}
+
+fn join(words: &[&str]) -> Option<String> {
+    (!words.is_empty()).then(|| words.join(" "))
+}
@@ -13,6 +13,9 @@
 
 use serde::{Serialize, Deserialize};
 
+/// Reference types are shared with style conditions.
+pub use crate::style::ReferenceTypes;
+
 //pub type TemplateKey = String;
 //pub type Id = String;
 //pub type TitleString = String;
@@ -1033,14 +1036,6 @@ pub enum Locators {
     Page,
 }
 
-#[derive(Serialize, Deserialize)]
-#[serde(rename_all = "snake_case")]
-pub enum ReferenceTypes {
-    Article,
-    Book,
-    Chapter,
-}
-
 #[derive(Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 pub enum TemplateComponentFormat {
@@ -1504,27 +1499,99 @@ pub enum Title {
 pub struct TitleStructured {
     pub full: Option<String>,
     pub main: String,
+    /// The short title, for later citations; the main title when absent.
+    pub short: Option<String>,
+    #[serde(default)]
     pub sub: Vec<String>,
 }
 
 /// Input reference data.
 #[derive(Serialize, Deserialize)]
 pub struct InputReference {
+    #[serde(rename = "abstract")]
+    pub input_reference_abstract: Option<String>,
     pub accessed: Option<String>,
+    /// The archive or collection holding the item.
+    pub archive: Option<String>,
     pub author: Option<Vec<Contributor>>,
+    /// The editors of the series or collection in which the item appears.
+    #[serde(rename = "collection-editor")]
+    pub collection_editor: Option<Vec<Contributor>>,
+    pub composer: Option<Vec<Contributor>>,
+    /// The authors of the container in which the item appears, such as a book for a chapter.
+    #[serde(rename = "container-author")]
+    pub container_author: Option<Vec<Contributor>>,
+    /// The title of the container in which the item appears, such as a journal or a book.
+    #[serde(rename = "container-title")]
+    pub container_title: Option<TitleUnion>,
+    pub director: Option<Vec<Contributor>>,
+    #[serde(alias = "DOI")]
+    pub doi: Option<String>,
+    pub edition: Option<NumberOrString>,
     pub editor: Option<Vec<Contributor>>,
+    /// The kind of work, such as "PhD dissertation".
+    pub genre: Option<String>,
+    pub illustrator: Option<Vec<Contributor>>,
+    pub interviewer: Option<Vec<Contributor>>,
+    #[serde(alias = "ISBN")]
+    pub isbn: Option<String>,
+    #[serde(alias = "ISSN")]
+    pub issn: Option<String>,
+    pub issue: Option<NumberOrString>,
     pub issued: Option<String>,
+    /// The language of the reference, as a language tag such as "de" or "fr-CA".
+    pub language: Option<String>,
+    /// The medium of the item, such as "DVD".
+    pub medium: Option<String>,
+    pub note: Option<String>,
+    /// The page or page range of the item within its container.
+    pub pages: Option<NumberOrString>,
     pub publisher: Option<String>,
+    #[serde(rename = "publisher-place")]
+    pub publisher_place: Option<String>,
+    pub recipient: Option<Vec<Contributor>>,
     pub title: Option<TitleUnion>,
+    pub translator: Option<Vec<Contributor>>,
     #[serde(rename = "type")]
     pub input_reference_type: Option<ReferenceTypes>,
+    #[serde(alias = "URL")]
+    pub url: Option<String>,
+    pub volume: Option<NumberOrString>,
 }
 
+/// A number variable, given as a number or as text such as "12-14" or "2nd".
 #[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum NumberOrString {
+    Number(i64),
+    String(String),
+}
+
+impl std::fmt::Display for NumberOrString {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            NumberOrString::Number(number) => write!(f, "{}", number),
+            NumberOrString::String(value) => f.write_str(value),
+        }
+    }
+}
+
+/// A contributor name, given either in parts or as a single `name` string.
+#[derive(Default, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
 pub struct Contributor {
-    pub name: String,
+    pub dropping_particle: Option<String>,
+    pub family: Option<String>,
+    pub given: Option<String>,
+    /// A name rendered as is, such as that of an organisation.
+    pub literal: Option<String>,
+    /// The name as a single string, in sort ("Doe, Jane") or display ("Jane Doe") order.
+    pub name: Option<String>,
+    pub non_dropping_particle: Option<String>,
+    /// Whether to parse the `name` string into parts; a name that is not parsed is literal.
     pub parse: Option<bool>,
     pub role: Option<String>,
+    pub suffix: Option<String>,
 }
 
 #[derive(Serialize, Deserialize)]
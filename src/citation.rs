@@ -18,7 +18,7 @@ pub struct Citation {
     /// Local citation rendering option; aka command or style.
     ///
     /// - `integral` places the author inline in the text; also known as "narrative" or "in text"
-    /// citations.
+    ///   citations.
     /// - `nonIntegral` places the author in the citation.
     ///
     /// Both are more general than author-date styles, and can apply to any citation style.
@@ -33,7 +33,7 @@ pub struct Citation {
 /// Local citation rendering option; aka command or style.
 ///
 /// - `integral` places the author inline in the text; also known as "narrative" or "in text"
-/// citations.
+///   citations.
 /// - `nonIntegral` places the author in the citation.
 ///
 /// Both are more general than author-date styles, and can apply to any citation style.
@@ -58,6 +58,7 @@ pub struct Reference {
 }
 
 /// A key-value object, or a string.
+#[allow(clippy::large_enum_variant)]
 #[derive(Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum Locator {
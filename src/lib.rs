@@ -0,0 +1,17 @@
+#[macro_use]
+extern crate serde_derive;
+extern crate serde_json;
+extern crate serde_yaml;
+
+#[allow(unused_imports)]
+pub mod bibliography;
+
+#[allow(unused_imports)]
+pub mod citation;
+
+#[allow(unused_imports)]
+pub mod style;
+
+pub mod locale;
+pub mod output;
+pub mod processor;
@@ -0,0 +1,453 @@
+//! Locales: the terms of a language, looked up by name, form and plurality.
+//!
+//! Locale files hold `LocalizationTerms` in YAML or JSON. A `Locales` registry holds the
+//! built-in locales, extended from a directory of locale files, and resolves a language tag
+//! to a `Locale`, which looks terms up in the locale of the tag, then in that of its base
+//! language, then in en-US.
+
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use crate::bibliography::date::Season;
+use crate::style::{ContributorRoles, Form, LocalizedTermName};
+
+/// The language tag of the final fallback locale.
+const FALLBACK: &str = "en-us";
+
+/// The preferred region of each language, as in CSL, whose locale stands in for the other
+/// regions; languages not listed prefer the region with their own code, as "de-DE".
+const PREFERRED_REGIONS: &[(&str, &str)] = &[
+    ("da", "da-dk"),
+    ("el", "el-gr"),
+    ("en", "en-us"),
+    ("et", "et-ee"),
+    ("fa", "fa-ir"),
+    ("he", "he-il"),
+    ("ja", "ja-jp"),
+    ("ko", "ko-kr"),
+    ("nb", "nb-no"),
+    ("nn", "nn-no"),
+    ("sr", "sr-rs"),
+    ("sv", "sv-se"),
+    ("uk", "uk-ua"),
+    ("vi", "vi-vn"),
+    ("zh", "zh-cn"),
+];
+
+/// The built-in locale files, embedded in the crate.
+const BUILT_IN: &[(&str, &str)] = &[
+    ("de-DE.yaml", include_str!("../locales/de-DE.yaml")),
+    ("en-GB.yaml", include_str!("../locales/en-GB.yaml")),
+    ("en-US.yaml", include_str!("../locales/en-US.yaml")),
+    ("es-ES.yaml", include_str!("../locales/es-ES.yaml")),
+    ("fr-FR.yaml", include_str!("../locales/fr-FR.yaml")),
+    ("ja-JP.yaml", include_str!("../locales/ja-JP.yaml")),
+    ("zh-CN.yaml", include_str!("../locales/zh-CN.yaml")),
+];
+
+/// The terms of a language, as read from a locale file.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LocalizationTerms {
+    pub description: Option<String>,
+    /// The language tag of the locale, such as "en-US" or "de".
+    pub locale: String,
+    pub punctuation_in_quote: Option<bool>,
+    /// The letter groups initialized as one letter, such as "Gy" and "Sz" in Hungarian.
+    pub initial_digraphs: Option<Vec<String>>,
+    /// The string between a title and its subtitles, such as ": ".
+    pub subtitle_delimiter: Option<String>,
+    /// The patterns of dates; those not given are taken from the fallback locales.
+    pub dates: Option<DatePatterns>,
+    /// The terms of the locale; terms not given are looked up in the fallback locales.
+    #[serde(default)]
+    pub terms: HashMap<LocalizedTermName, LocalizedTerms>,
+    pub title: Option<String>,
+}
+
+/// How the parts of a date are written, with `{year}`, `{month}` and `{day}` placeholders,
+/// and times of day, with `{hour}`, `{minute}`, `{second}` and `{period}` placeholders.
+///
+/// Textual patterns take month names, and numeric patterns month numbers. Time patterns with
+/// a `{period}` are on the 12-hour clock, with the `am` or `pm` string of the locale, and the
+/// others on the 24-hour clock.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DatePatterns {
+    /// A year alone, such as "{year}".
+    pub year: Option<String>,
+    /// A full date, such as "{month} {day}, {year}".
+    pub date: Option<String>,
+    pub year_month: Option<String>,
+    pub month_day: Option<String>,
+    /// A full numeric date, such as "{month}/{day}/{year}".
+    pub numeric_date: Option<String>,
+    pub numeric_year_month: Option<String>,
+    pub numeric_month_day: Option<String>,
+    /// A time of day, such as "{hour}:{minute}:{second} {period}".
+    pub time: Option<String>,
+    /// A time of day without seconds, such as "{hour}:{minute} {period}".
+    pub short_time: Option<String>,
+    /// The period of times before noon on the 12-hour clock, such as "AM".
+    pub am: Option<String>,
+    /// The period of times from noon on the 12-hour clock, such as "PM".
+    pub pm: Option<String>,
+}
+
+/// A term in one form, or in several forms.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum LocalizedTerms {
+    Term(LocalizedTerm),
+    Forms(Vec<LocalizedTerm>),
+}
+
+/// A form of a term, in the singular and the plural.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct LocalizedTerm {
+    /// The form of the term; the long form when absent.
+    pub format: Option<LocalizedTermFormat>,
+    pub multiple: Option<String>,
+    pub single: Option<String>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum LocalizedTermFormat {
+    Short,
+    Symbol,
+    Verb,
+    VerbShort,
+}
+
+impl LocalizedTerms {
+    fn forms(&self) -> &[LocalizedTerm] {
+        match self {
+            LocalizedTerms::Term(term) => std::slice::from_ref(term),
+            LocalizedTerms::Forms(forms) => forms,
+        }
+    }
+}
+
+impl LocalizationTerms {
+    /// Parse a locale file, in JSON or else YAML.
+    fn parse(content: &str, json: bool) -> Result<Self, String> {
+        if json {
+            serde_json::from_str(content).map_err(|err| err.to_string())
+        } else {
+            serde_yaml::from_str(content).map_err(|err| err.to_string())
+        }
+    }
+
+    /// Extend the locale with another of the same language, whose values take precedence.
+    fn merge(&mut self, other: LocalizationTerms) {
+        self.description = other.description.or(self.description.take());
+        self.punctuation_in_quote = other.punctuation_in_quote.or(self.punctuation_in_quote);
+        self.initial_digraphs = other.initial_digraphs.or(self.initial_digraphs.take());
+        self.subtitle_delimiter = other.subtitle_delimiter.or(self.subtitle_delimiter.take());
+        self.title = other.title.or(self.title.take());
+        self.terms.extend(other.terms);
+        self.dates = match (self.dates.take(), other.dates) {
+            (Some(dates), Some(other)) => Some(DatePatterns {
+                year: other.year.or(dates.year),
+                date: other.date.or(dates.date),
+                year_month: other.year_month.or(dates.year_month),
+                month_day: other.month_day.or(dates.month_day),
+                numeric_date: other.numeric_date.or(dates.numeric_date),
+                numeric_year_month: other.numeric_year_month.or(dates.numeric_year_month),
+                numeric_month_day: other.numeric_month_day.or(dates.numeric_month_day),
+                time: other.time.or(dates.time),
+                short_time: other.short_time.or(dates.short_time),
+                am: other.am.or(dates.am),
+                pm: other.pm.or(dates.pm),
+            }),
+            (dates, other) => other.or(dates),
+        };
+    }
+
+    /// A term in a form, falling back to other forms of the term: "verb-short" to "verb",
+    /// "symbol" to "short", and any form to the long form. The plural falls back to the
+    /// singular.
+    pub fn term(
+        &self,
+        name: &LocalizedTermName,
+        format: Option<LocalizedTermFormat>,
+        plural: bool,
+    ) -> Option<&str> {
+        let forms = self.terms.get(name)?.forms();
+        fallback_formats(format).iter().find_map(|format| {
+            let term = forms.iter().find(|term| term.format == *format)?;
+            match (plural, &term.multiple) {
+                (true, Some(multiple)) => Some(multiple.as_str()),
+                _ => term.single.as_deref(),
+            }
+        })
+    }
+}
+
+fn fallback_formats(format: Option<LocalizedTermFormat>) -> &'static [Option<LocalizedTermFormat>] {
+    use LocalizedTermFormat::{Short, Symbol, Verb, VerbShort};
+    match format {
+        None => &[None],
+        Some(Short) => &[Some(Short), None],
+        Some(Symbol) => &[Some(Symbol), Some(Short), None],
+        Some(Verb) => &[Some(Verb), None],
+        Some(VerbShort) => &[Some(VerbShort), Some(Verb), None],
+    }
+}
+
+/// Errors raised while loading locale files.
+#[derive(Debug)]
+pub enum LocaleError {
+    /// A locale file or directory could not be read.
+    Read(PathBuf, io::Error),
+    /// A locale file does not hold localization terms.
+    Parse(PathBuf, String),
+}
+
+impl fmt::Display for LocaleError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LocaleError::Read(path, err) => {
+                write!(f, "unable to read locale {}: {}", path.display(), err)
+            }
+            LocaleError::Parse(path, message) => {
+                write!(f, "invalid locale file {}: {}", path.display(), message)
+            }
+        }
+    }
+}
+
+impl std::error::Error for LocaleError {}
+
+/// A registry of locales, by language tag.
+pub struct Locales {
+    /// The locales by lowercased language tag.
+    locales: HashMap<String, LocalizationTerms>,
+}
+
+impl Locales {
+    /// A registry of the built-in locales.
+    pub fn new() -> Self {
+        let mut locales = Locales::empty();
+        for (name, content) in BUILT_IN {
+            let terms = LocalizationTerms::parse(content, false)
+                .unwrap_or_else(|err| panic!("invalid built-in locale {}: {}", name, err));
+            locales.insert(terms);
+        }
+        locales
+    }
+
+    /// A registry without locales, which renders built-in en-US terms.
+    pub fn empty() -> Self {
+        Locales {
+            locales: HashMap::new(),
+        }
+    }
+
+    /// Add a locale, its values overriding those of any locale with the same language tag.
+    pub fn insert(&mut self, terms: LocalizationTerms) {
+        match self.locales.entry(terms.locale.to_lowercase()) {
+            Entry::Occupied(mut entry) => entry.get_mut().merge(terms),
+            Entry::Vacant(entry) => {
+                entry.insert(terms);
+            }
+        }
+    }
+
+    /// Read a locale file, in JSON or else YAML.
+    pub fn load_file(&mut self, path: &Path) -> Result<(), LocaleError> {
+        let content =
+            fs::read_to_string(path).map_err(|err| LocaleError::Read(path.to_path_buf(), err))?;
+        let json = path.extension().is_some_and(|ext| ext == "json");
+        let terms = LocalizationTerms::parse(&content, json)
+            .map_err(|message| LocaleError::Parse(path.to_path_buf(), message))?;
+        self.insert(terms);
+        Ok(())
+    }
+
+    /// Read the `.json`, `.yaml` and `.yml` locale files of a directory, in name order.
+    pub fn load_dir(&mut self, dir: &Path) -> Result<(), LocaleError> {
+        let read_error = |err| LocaleError::Read(dir.to_path_buf(), err);
+        let mut paths = Vec::new();
+        for entry in fs::read_dir(dir).map_err(read_error)? {
+            let path = entry.map_err(read_error)?.path();
+            let extension = path.extension().and_then(|ext| ext.to_str());
+            if matches!(extension, Some("json" | "yaml" | "yml")) {
+                paths.push(path);
+            }
+        }
+        paths.sort();
+        paths.iter().try_for_each(|path| self.load_file(path))
+    }
+
+    /// The locale for a language tag.
+    ///
+    /// A regional locale such as "de-AT" falls back to the locale of its base language: "de",
+    /// else that of the preferred region of the language, "de-DE", else the first other "de"
+    /// locale by tag. The preferred region of "en" is "en-US" and that of "zh" "zh-CN".
+    pub fn locale(&self, tag: &str) -> Locale<'_> {
+        let tag = tag.to_lowercase();
+        let language = tag.split('-').next().unwrap_or_default();
+        let preferred = PREFERRED_REGIONS
+            .iter()
+            .find(|(base, _)| *base == language)
+            .and_then(|(_, preferred)| self.locales.get(*preferred));
+        let base = self
+            .locales
+            .get(language)
+            .or(preferred)
+            .or_else(|| self.locales.get(&format!("{0}-{0}", language)))
+            .or_else(|| {
+                self.locales
+                    .iter()
+                    .filter(|(key, _)| key.split('-').next() == Some(language))
+                    .min_by_key(|(key, _)| *key)
+                    .map(|(_, terms)| terms)
+            });
+        Locale {
+            chain: [self.locales.get(&tag), base, self.locales.get(FALLBACK)],
+        }
+    }
+}
+
+impl Default for Locales {
+    fn default() -> Self {
+        Locales::new()
+    }
+}
+
+/// The locales in which to look up terms, in order.
+///
+/// The default locale has the built-in en-US terms only.
+#[derive(Clone, Copy, Default)]
+pub struct Locale<'l> {
+    chain: [Option<&'l LocalizationTerms>; 3],
+}
+
+impl<'l> Locale<'l> {
+    /// The locales in order, ending with the built-in en-US locale.
+    fn locales(&self) -> impl Iterator<Item = &'l LocalizationTerms> {
+        self.chain.into_iter().flatten().chain([en_us()])
+    }
+
+    /// The language tag of the first locale.
+    pub fn tag(&self) -> &'l str {
+        self.locales().next().map_or("en-US", |terms| &terms.locale)
+    }
+
+    /// Whether commas and periods go inside closing quotation marks, per the first locale
+    /// that says.
+    pub fn punctuation_in_quote(&self) -> bool {
+        self.locales()
+            .find_map(|terms| terms.punctuation_in_quote)
+            .unwrap_or(false)
+    }
+
+    /// The letter groups initialized as one letter, per the first locale that says.
+    pub fn initial_digraphs(&self) -> &'l [String] {
+        self.locales()
+            .find_map(|terms| terms.initial_digraphs.as_deref())
+            .unwrap_or_default()
+    }
+
+    /// The string between a title and its subtitles, from the first locale that has it.
+    pub fn subtitle_delimiter(&self) -> &'l str {
+        self.locales()
+            .find_map(|terms| terms.subtitle_delimiter.as_deref())
+            .unwrap_or_default()
+    }
+
+    /// A term in a form, from the first locale that has it.
+    pub fn term(
+        &self,
+        name: &LocalizedTermName,
+        format: Option<LocalizedTermFormat>,
+        plural: bool,
+    ) -> &'l str {
+        self.locales()
+            .find_map(|terms| terms.term(name, format, plural))
+            .unwrap_or_default()
+    }
+
+    /// A date pattern, from the first locale that has it.
+    pub fn date_pattern(&self, get: impl Fn(&'l DatePatterns) -> Option<&'l String>) -> &'l str {
+        self.locales()
+            .find_map(|terms| terms.dates.as_ref().and_then(&get))
+            .map(String::as_str)
+            .unwrap_or_default()
+    }
+
+    /// The term for a contributor role in a display form.
+    pub fn role(&self, role: &ContributorRoles, form: &Form, plural: bool) -> &'l str {
+        let name = match role {
+            ContributorRoles::Author => LocalizedTermName::Author,
+            ContributorRoles::CollectionEditor => LocalizedTermName::CollectionEditor,
+            ContributorRoles::Composer => LocalizedTermName::Composer,
+            ContributorRoles::ContainerAuthor => LocalizedTermName::ContainerAuthor,
+            ContributorRoles::Director => LocalizedTermName::Director,
+            ContributorRoles::Editor => LocalizedTermName::Editor,
+            ContributorRoles::Illustrator => LocalizedTermName::Illustrator,
+            ContributorRoles::Interviewer => LocalizedTermName::Interviewer,
+            ContributorRoles::Publisher => LocalizedTermName::Publisher,
+            ContributorRoles::Recipient => LocalizedTermName::Recipient,
+            ContributorRoles::Translator => LocalizedTermName::Translator,
+        };
+        let format = match form {
+            Form::Long => None,
+            Form::Short => Some(LocalizedTermFormat::Short),
+            Form::Verb => Some(LocalizedTermFormat::Verb),
+            Form::VerbShort => Some(LocalizedTermFormat::VerbShort),
+        };
+        self.term(&name, format, plural)
+    }
+
+    /// The name of a month, from 1 to 12, in the long or short form.
+    pub fn month(&self, month: u8, short: bool) -> &'l str {
+        const MONTHS: [LocalizedTermName; 12] = [
+            LocalizedTermName::Month01,
+            LocalizedTermName::Month02,
+            LocalizedTermName::Month03,
+            LocalizedTermName::Month04,
+            LocalizedTermName::Month05,
+            LocalizedTermName::Month06,
+            LocalizedTermName::Month07,
+            LocalizedTermName::Month08,
+            LocalizedTermName::Month09,
+            LocalizedTermName::Month10,
+            LocalizedTermName::Month11,
+            LocalizedTermName::Month12,
+        ];
+        let name = &MONTHS[usize::from(month.clamp(1, 12)) - 1];
+        self.term(
+            name,
+            Some(LocalizedTermFormat::Short).filter(|_| short),
+            false,
+        )
+    }
+
+    /// The name of a season.
+    pub fn season(&self, season: &Season) -> &'l str {
+        let name = match season {
+            Season::Spring => LocalizedTermName::Season01,
+            Season::Summer => LocalizedTermName::Season02,
+            Season::Autumn => LocalizedTermName::Season03,
+            Season::Winter => LocalizedTermName::Season04,
+        };
+        self.term(&name, None, false)
+    }
+}
+
+/// The built-in en-US locale.
+fn en_us() -> &'static LocalizationTerms {
+    static EN_US: OnceLock<LocalizationTerms> = OnceLock::new();
+    EN_US.get_or_init(|| {
+        LocalizationTerms::parse(include_str!("../locales/en-US.yaml"), false)
+            .expect("invalid built-in locale en-US.yaml")
+    })
+}
@@ -1,24 +1,36 @@
 use std::env;
 use std::fs;
+use std::path::Path;
 
-#[macro_use]
-extern crate serde_derive;
-extern crate serde_json;
-extern crate serde_yaml;
-
-#[allow(unused_imports)]
-mod bibliography;
-use bibliography::InputBibliography as Bib;
-
-#[allow(unused_imports)]
-mod style;
-use style::Style;
+use csln_rs::bibliography::InputBibliography as Bib;
+use csln_rs::citation::Citation;
+use csln_rs::locale::Locales;
+use csln_rs::output::output_format;
+use csln_rs::processor::{flatten_style, Processor};
+use csln_rs::style::Style;
 
 fn main() {
-    // Get the command line arguments.
-    let args: Vec<String> = env::args().collect();
-    if args.len() != 3 {
-        panic!("Please provide style path and bibliography path as command line arguments.");
+    // Get the command line arguments, and the `--locale <tag>`, `--locales <dir>` and
+    // `--format <plain|html|markdown|latex|rtf|ooxml>` options.
+    let mut args: Vec<String> = Vec::new();
+    let mut locale_tag = None;
+    let mut locales_dir = None;
+    let mut output = output_format("plain").unwrap();
+    let mut arguments = env::args();
+    while let Some(arg) = arguments.next() {
+        match arg.as_str() {
+            "--locale" => locale_tag = Some(arguments.next().expect("Missing locale tag")),
+            "--locales" => locales_dir = Some(arguments.next().expect("Missing locales directory")),
+            "--format" => {
+                let name = arguments.next().expect("Missing output format");
+                output = output_format(&name)
+                    .unwrap_or_else(|| panic!("Unsupported output format: {}", name));
+            }
+            _ => args.push(arg),
+        }
+    }
+    if args.len() != 3 && args.len() != 4 {
+        panic!("Please provide style path, bibliography path and, optionally, citations path as command line arguments.");
     }
     let style_path = &args[1];
     let bib_path = &args[2];
@@ -33,6 +45,8 @@ fn main() {
     } else {
         panic!("Unsupported file format for style file.");
     };
+    let style = flatten_style(&style)
+        .unwrap_or_else(|err| panic!("Unable to resolve style templates: {}", err));
 
     // Parse the bibliography file.
     let bib_json = fs::read_to_string(bib_path)
@@ -45,7 +59,59 @@ fn main() {
         panic!("Unsupported file format for bibliography file.");
     };
 
-    // Do something with the style and bibliography data.
-    println!("The name of the style is: {}", serde_json::to_string(&style.title).unwrap());
-    println!("The number of entries in the bibliography is: {}", bib.len());
-}
\ No newline at end of file
+    // Parse the citations file, if any.
+    let citations: Vec<Citation> = match args.get(3) {
+        Some(citations_path) => {
+            let citations_json = fs::read_to_string(citations_path)
+                .expect("Unable to read citations file");
+            if citations_path.ends_with(".json") {
+                serde_json::from_str(&citations_json).unwrap()
+            } else if citations_path.ends_with(".yaml") || citations_path.ends_with(".yml") {
+                serde_yaml::from_str(&citations_json).unwrap()
+            } else {
+                panic!("Unsupported file format for citations file.");
+            }
+        }
+        None => Vec::new(),
+    };
+
+    // Load the locale files, if any.
+    let mut locales = Locales::new();
+    if let Some(dir) = &locales_dir {
+        locales
+            .load_dir(Path::new(dir))
+            .unwrap_or_else(|err| panic!("Unable to load locales: {}", err));
+    }
+
+    // Render the citations and the bibliography as a document of the output format, one
+    // paragraph per line; the status lines go to standard error.
+    eprintln!("The name of the style is: {}", serde_json::to_string(&style.title).unwrap());
+    eprintln!("The number of entries in the bibliography is: {}", bib.len());
+    let processor = Processor::new(&style, &bib)
+        .with_citations(&citations)
+        .with_locales(&locales, locale_tag.as_deref().unwrap_or("en-US"))
+        .with_output(output);
+    let mut lines = Vec::new();
+    for citation in &citations {
+        match processor.render_citation(citation) {
+            Ok(value) => lines.push(output.paragraph(&value)),
+            Err(err) => eprintln!("Unable to render citation: {}", err),
+        }
+    }
+    if !citations.is_empty() {
+        lines.push(String::new());
+    }
+    if let Some(heading) = style.bibliography.as_ref().and_then(|b| b.heading.as_ref()) {
+        lines.push(output.heading(heading));
+        lines.push(String::new());
+    }
+    for group in processor.render_grouped_bibliography() {
+        if let Some(heading) = &group.heading {
+            lines.push(output.heading(heading));
+        }
+        for entry in group.entries {
+            lines.push(entry.value);
+        }
+    }
+    println!("{}", output.document(&lines.join("\n")));
+}
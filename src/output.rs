@@ -0,0 +1,413 @@
+//! Output formats: how rendered text is escaped and marked up.
+//!
+//! The processor renders reference data as text escaped for an `OutputFormat`, and marks up
+//! components with the emphasis, strong emphasis and classes of the format. Plain text,
+//! HTML, Markdown, LaTeX, RTF and Office Open XML are built in; other formats implement the
+//! trait.
+
+/// A format in which to write citations and bibliographies.
+pub trait OutputFormat {
+    /// Escape text for the format.
+    fn text(&self, value: &str) -> String;
+
+    /// Emphasize a marked-up value, as in italics.
+    fn emph(&self, value: &str) -> String;
+
+    /// Strongly emphasize a marked-up value, as in bold.
+    fn bold(&self, value: &str) -> String;
+
+    /// Mark up a rendered component with the class of what it renders, such as "title" or
+    /// "author".
+    fn component(&self, value: &str, _class: &str) -> String {
+        value.to_string()
+    }
+
+    /// Mark up a bibliography entry, as a paragraph with a hanging indent when the list style
+    /// of the bibliography asks for one.
+    fn entry(&self, value: &str, _hanging_indent: bool) -> String {
+        value.to_string()
+    }
+
+    /// Mark up a citation.
+    fn citation(&self, value: &str) -> String {
+        value.to_string()
+    }
+
+    /// Mark up the text of a bibliography or group heading.
+    fn heading(&self, value: &str) -> String {
+        self.text(value)
+    }
+
+    /// The length of a marked-up value without the markup closing it, such as closing tags,
+    /// so that punctuation can be placed next to the text.
+    fn markup_end(&self, value: &str) -> usize {
+        value.len()
+    }
+
+    /// Mark up a marked-up value, such as a citation, as a paragraph of its own.
+    fn paragraph(&self, value: &str) -> String {
+        value.to_string()
+    }
+
+    /// Wrap paragraphs, one per line, in a complete document of the format. Formats whose
+    /// paragraphs can stand alone, such as HTML, leave them as they are.
+    fn document(&self, paragraphs: &str) -> String {
+        paragraphs.to_string()
+    }
+}
+
+/// The output format of a name: "plain", "html", "markdown", "latex", "rtf" or "ooxml".
+pub fn output_format(name: &str) -> Option<&'static dyn OutputFormat> {
+    match name {
+        "plain" => Some(&Plain),
+        "html" => Some(&Html),
+        "markdown" => Some(&Markdown),
+        "latex" => Some(&Latex),
+        "rtf" => Some(&Rtf),
+        "ooxml" => Some(&Ooxml),
+        _ => None,
+    }
+}
+
+/// Plain text, without markup.
+pub struct Plain;
+
+impl OutputFormat for Plain {
+    fn text(&self, value: &str) -> String {
+        value.to_string()
+    }
+
+    fn emph(&self, value: &str) -> String {
+        value.to_string()
+    }
+
+    fn bold(&self, value: &str) -> String {
+        value.to_string()
+    }
+}
+
+/// HTML, with components in `span` elements of class `csl-<class>` and entries in `div`
+/// elements of class `csl-entry`.
+pub struct Html;
+
+impl OutputFormat for Html {
+    fn text(&self, value: &str) -> String {
+        value
+            .replace('&', "&amp;")
+            .replace('<', "&lt;")
+            .replace('>', "&gt;")
+    }
+
+    fn emph(&self, value: &str) -> String {
+        format!("<i>{}</i>", value)
+    }
+
+    fn bold(&self, value: &str) -> String {
+        format!("<b>{}</b>", value)
+    }
+
+    fn component(&self, value: &str, class: &str) -> String {
+        format!("<span class=\"csl-{}\">{}</span>", class, value)
+    }
+
+    fn entry(&self, value: &str, _hanging_indent: bool) -> String {
+        format!("<div class=\"csl-entry\">{}</div>", value)
+    }
+
+    fn heading(&self, value: &str) -> String {
+        format!("<h2>{}</h2>", self.text(value))
+    }
+
+    fn markup_end(&self, value: &str) -> usize {
+        closing_tags_end(value)
+    }
+}
+
+/// Markdown, with `*` for emphasis and `**` for strong emphasis.
+pub struct Markdown;
+
+impl OutputFormat for Markdown {
+    fn text(&self, value: &str) -> String {
+        let mut result = String::with_capacity(value.len());
+        for c in value.chars() {
+            if matches!(c, '\\' | '*' | '_' | '[' | ']' | '`' | '<') {
+                result.push('\\');
+            }
+            result.push(c);
+        }
+        result
+    }
+
+    fn emph(&self, value: &str) -> String {
+        format!("*{}*", value)
+    }
+
+    fn bold(&self, value: &str) -> String {
+        format!("**{}**", value)
+    }
+
+    fn heading(&self, value: &str) -> String {
+        format!("## {}", self.text(value))
+    }
+
+    fn markup_end(&self, value: &str) -> usize {
+        let mut end = value.len();
+        while value[..end].ends_with('*') && !value[..end - 1].ends_with('\\') {
+            end -= 1;
+        }
+        end
+    }
+}
+
+/// LaTeX, with `\emph` for emphasis, `\textbf` for strong emphasis and `--` for en dashes.
+pub struct Latex;
+
+impl OutputFormat for Latex {
+    fn text(&self, value: &str) -> String {
+        let mut result = String::with_capacity(value.len());
+        for c in value.chars() {
+            match c {
+                '&' | '%' | '$' | '#' | '_' | '{' | '}' => {
+                    result.push('\\');
+                    result.push(c);
+                }
+                '\\' => result.push_str("\\textbackslash{}"),
+                '~' => result.push_str("\\textasciitilde{}"),
+                '^' => result.push_str("\\textasciicircum{}"),
+                '–' => result.push_str("--"),
+                '\u{a0}' => result.push('~'),
+                _ => result.push(c),
+            }
+        }
+        result
+    }
+
+    fn emph(&self, value: &str) -> String {
+        format!("\\emph{{{}}}", value)
+    }
+
+    fn bold(&self, value: &str) -> String {
+        format!("\\textbf{{{}}}", value)
+    }
+
+    fn heading(&self, value: &str) -> String {
+        format!("\\section*{{{}}}", self.text(value))
+    }
+
+    fn markup_end(&self, value: &str) -> usize {
+        let mut end = value.len();
+        while value[..end].ends_with('}') && !value[..end - 1].ends_with(['\\', '{']) {
+            end -= 1;
+        }
+        end
+    }
+}
+
+/// RTF, with `\i` for emphasis and `\b` for strong emphasis, entries as paragraphs, and
+/// documents in an `\rtf1` group.
+pub struct Rtf;
+
+impl Rtf {
+    /// Write the characters beyond ASCII as Unicode escapes.
+    fn unicode(value: &str) -> String {
+        let mut result = String::with_capacity(value.len());
+        for c in value.chars() {
+            if c.is_ascii() {
+                result.push(c);
+            } else {
+                for unit in c.encode_utf16(&mut [0; 2]) {
+                    result.push_str(&format!("\\u{}?", *unit as i16));
+                }
+            }
+        }
+        result
+    }
+}
+
+impl OutputFormat for Rtf {
+    fn text(&self, value: &str) -> String {
+        let mut result = String::with_capacity(value.len());
+        for c in value.chars() {
+            if matches!(c, '\\' | '{' | '}') {
+                result.push('\\');
+            }
+            result.push(c);
+        }
+        result
+    }
+
+    fn emph(&self, value: &str) -> String {
+        format!("{{\\i {}}}", value)
+    }
+
+    fn bold(&self, value: &str) -> String {
+        format!("{{\\b {}}}", value)
+    }
+
+    fn entry(&self, value: &str, hanging_indent: bool) -> String {
+        let indent = if hanging_indent {
+            "\\li720\\fi-720"
+        } else {
+            ""
+        };
+        format!("{{\\pard{} {}\\par}}", indent, Rtf::unicode(value))
+    }
+
+    fn citation(&self, value: &str) -> String {
+        Rtf::unicode(value)
+    }
+
+    fn heading(&self, value: &str) -> String {
+        format!("{{\\pard\\b {}\\par}}", Rtf::unicode(&self.text(value)))
+    }
+
+    fn markup_end(&self, value: &str) -> usize {
+        let mut end = value.len();
+        while value[..end].ends_with('}') && !value[..end - 1].ends_with('\\') {
+            end -= 1;
+        }
+        end
+    }
+
+    fn paragraph(&self, value: &str) -> String {
+        self.entry(value, false)
+    }
+
+    fn document(&self, paragraphs: &str) -> String {
+        format!("{{\\rtf1\\ansi\\deff0\n{}\n}}", paragraphs)
+    }
+}
+
+/// The namespace of the WordprocessingML elements of Office Open XML.
+const OOXML_NAMESPACE: &str = "http://schemas.openxmlformats.org/wordprocessingml/2006/main";
+
+/// Office Open XML: text in `w:r` runs, with `w:i` for emphasis and `w:b` for strong
+/// emphasis, entries in `w:p` paragraphs, and documents as the `w:document` main part.
+pub struct Ooxml;
+
+impl Ooxml {
+    fn run(text: &str, bold: bool, italic: bool) -> String {
+        let properties = match (bold, italic) {
+            (false, false) => String::new(),
+            _ => format!(
+                "<w:rPr>{}{}</w:rPr>",
+                if bold { "<w:b/>" } else { "" },
+                if italic { "<w:i/>" } else { "" }
+            ),
+        };
+        format!(
+            "<w:r>{}<w:t xml:space=\"preserve\">{}</w:t></w:r>",
+            properties, text
+        )
+    }
+
+    /// Put the text between the runs of a value, such as delimiters, in runs of its own.
+    fn runs(value: &str) -> String {
+        let mut result = String::with_capacity(value.len());
+        let mut rest = value;
+        while !rest.is_empty() {
+            let start = rest.find("<w:r>").unwrap_or(rest.len());
+            if start > 0 {
+                result.push_str(&Ooxml::run(&Html.text(&rest[..start]), false, false));
+            }
+            rest = &rest[start..];
+            match rest.find("</w:r>") {
+                Some(end) => {
+                    result.push_str(&rest[..end + "</w:r>".len()]);
+                    rest = &rest[end + "</w:r>".len()..];
+                }
+                None => {
+                    result.push_str(rest);
+                    break;
+                }
+            }
+        }
+        result
+    }
+
+    /// Add bold or italic properties to the runs of a value.
+    fn format(value: &str, bold: bool, italic: bool) -> String {
+        let value = Ooxml::runs(value);
+        let mut result = String::with_capacity(value.len());
+        for run in value.split("<w:r>").filter(|run| !run.is_empty()) {
+            let (properties, rest) = match run
+                .strip_prefix("<w:rPr>")
+                .and_then(|run| run.split_once("</w:rPr>"))
+            {
+                Some((properties, rest)) => (properties, rest),
+                None => ("", run),
+            };
+            let text = rest
+                .strip_prefix("<w:t xml:space=\"preserve\">")
+                .and_then(|rest| rest.strip_suffix("</w:t></w:r>"))
+                .unwrap_or_default();
+            result.push_str(&Ooxml::run(
+                text,
+                bold || properties.contains("<w:b/>"),
+                italic || properties.contains("<w:i/>"),
+            ));
+        }
+        result
+    }
+}
+
+impl OutputFormat for Ooxml {
+    fn text(&self, value: &str) -> String {
+        Ooxml::run(&Html.text(value), false, false)
+    }
+
+    fn emph(&self, value: &str) -> String {
+        Ooxml::format(value, false, true)
+    }
+
+    fn bold(&self, value: &str) -> String {
+        Ooxml::format(value, true, false)
+    }
+
+    fn entry(&self, value: &str, hanging_indent: bool) -> String {
+        let properties = if hanging_indent {
+            "<w:pPr><w:ind w:left=\"720\" w:hanging=\"720\"/></w:pPr>"
+        } else {
+            ""
+        };
+        format!("<w:p>{}{}</w:p>", properties, Ooxml::runs(value))
+    }
+
+    fn citation(&self, value: &str) -> String {
+        Ooxml::runs(value)
+    }
+
+    fn heading(&self, value: &str) -> String {
+        format!("<w:p>{}</w:p>", Ooxml::run(&Html.text(value), true, false))
+    }
+
+    fn markup_end(&self, value: &str) -> usize {
+        closing_tags_end(value)
+    }
+
+    fn paragraph(&self, value: &str) -> String {
+        self.entry(value, false)
+    }
+
+    fn document(&self, paragraphs: &str) -> String {
+        format!(
+            concat!(
+                "<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"yes\"?>\n",
+                "<w:document xmlns:w=\"{}\"><w:body>\n{}\n</w:body></w:document>"
+            ),
+            OOXML_NAMESPACE, paragraphs
+        )
+    }
+}
+
+/// The length of a value without the closing tags it ends with.
+fn closing_tags_end(value: &str) -> usize {
+    let mut end = value.len();
+    while value[..end].ends_with('>') {
+        match value[..end].rfind("</") {
+            Some(start) if !value[start + 1..end].contains('<') => end = start,
+            _ => break,
+        }
+    }
+    end
+}
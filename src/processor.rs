@@ -0,0 +1,497 @@
+use std::cell::OnceCell;
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::bibliography::date::Edtf;
+use crate::bibliography::reference::{InputReference, NumberOrString};
+use crate::bibliography::InputBibliography;
+use crate::citation::{Citation, Reference as CiteReference};
+use crate::locale::{Locale, Locales, LocalizedTermFormat};
+use crate::output::{OutputFormat, Plain};
+use crate::style::{
+    Condition, ContributorRoles, Dates, Format, OptionGroup, Scope, SimpleTypes, Style, Substitute,
+    TemplateComponent, Titles, WrapPunctuation,
+};
+
+mod citation;
+pub mod condition;
+pub mod contributor;
+pub mod date;
+mod disambiguate;
+mod group;
+pub mod punctuation;
+mod sort;
+pub mod substitute;
+mod template;
+mod text_case;
+mod title;
+
+pub use disambiguate::{GivenNames, Hints};
+pub use group::ProcGroup;
+pub use template::{flatten_style, resolve_templates, TemplateError};
+
+use title::render_title;
+
+/// The string with which to join the top-level components of a bibliography entry.
+const ENTRY_DELIMITER: &str = ". ";
+
+/// The bibliography list style of entries with a hanging indent.
+const HANGING_INDENT: &str = "hanging-indent";
+
+/// The string with which to join the components of a nested template.
+const TEMPLATE_DELIMITER: &str = " ";
+
+/// A rendered bibliography entry.
+pub struct ProcEntry {
+    /// The key of the reference in the input bibliography.
+    pub id: String,
+    /// The formatted entry.
+    pub value: String,
+}
+
+/// The reference data and options available while rendering a template.
+#[derive(Clone)]
+pub struct RenderContext<'r> {
+    pub reference: &'r InputReference,
+    /// The citation reference, when rendering a citation.
+    pub cite: Option<&'r CiteReference>,
+    /// The option groups in effect, from the outermost to the innermost.
+    pub options: Vec<&'r OptionGroup>,
+    /// The variable substituted for a missing author.
+    pub substitute: Option<Substitute>,
+    /// How to render the reference to tell it apart from others.
+    pub hints: Hints,
+    /// The locale of terms: that of the reference when localizing per item, else the target
+    /// locale.
+    pub locale: Locale<'r>,
+}
+
+impl<'r> RenderContext<'r> {
+    pub fn new(reference: &'r InputReference, options: Vec<&'r OptionGroup>) -> Self {
+        let mut ctx = RenderContext {
+            reference,
+            cite: None,
+            options,
+            substitute: None,
+            hints: Hints::default(),
+            locale: Locale::default(),
+        };
+        ctx.substitute =
+            substitute::author_substitute(reference, ctx.option(|o| o.substitute.as_ref()));
+        ctx
+    }
+
+    /// The innermost value of an option.
+    pub fn option<T>(&self, get: impl Fn(&'r OptionGroup) -> Option<&'r T>) -> Option<&'r T> {
+        self.options.iter().rev().find_map(|options| get(options))
+    }
+
+    /// A context with an additional, innermost option group.
+    fn with_options(&self, options: Option<&'r OptionGroup>) -> RenderContext<'r> {
+        let mut ctx = self.clone();
+        ctx.options.extend(options);
+        ctx
+    }
+}
+
+/// Errors raised while processing citations and bibliographies.
+#[derive(Debug, PartialEq)]
+pub enum ProcessorError {
+    /// A citation refers to a reference that is not in the bibliography.
+    UnknownReference(String),
+    /// A cited reference has no `refID`.
+    MissingReferenceId,
+}
+
+impl fmt::Display for ProcessorError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ProcessorError::UnknownReference(id) => {
+                write!(f, "reference \"{}\" is not in the bibliography", id)
+            }
+            ProcessorError::MissingReferenceId => {
+                write!(f, "a cited reference has no refID")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ProcessorError {}
+
+/// Renders the references of an input bibliography according to a style.
+///
+/// The processor does not resolve named templates: a `templateKey` renders nothing. Styles
+/// that use named templates must first be flattened with [`flatten_style`].
+pub struct Processor<'a> {
+    style: &'a Style,
+    bibliography: &'a InputBibliography,
+    /// The citations of the document, in order.
+    citations: &'a [Citation],
+    /// The disambiguation hints of the cited references, by key, worked out when first
+    /// needed.
+    hints: OnceCell<HashMap<String, Hints>>,
+    /// The target locale.
+    locale: Locale<'a>,
+    /// The locales from which to take the locale of each reference when localizing per item.
+    locales: Option<&'a Locales>,
+    /// The format in which to write citations and bibliographies.
+    output: &'a dyn OutputFormat,
+}
+
+impl<'a> Processor<'a> {
+    pub fn new(style: &'a Style, bibliography: &'a InputBibliography) -> Self {
+        Processor {
+            style,
+            bibliography,
+            citations: &[],
+            hints: OnceCell::new(),
+            locale: Locale::default(),
+            locales: None,
+            output: &Plain,
+        }
+    }
+
+    /// Use the citations of a document, in order, for citation-dependent processing such as
+    /// sorting the bibliography in order of first citation and disambiguation.
+    pub fn with_citations(mut self, citations: &'a [Citation]) -> Self {
+        self.citations = citations;
+        self.hints = OnceCell::new();
+        self
+    }
+
+    /// Render terms in a locale rather than the built-in en-US terms.
+    pub fn with_locale(mut self, locale: Locale<'a>) -> Self {
+        self.locale = locale;
+        self.hints = OnceCell::new();
+        self
+    }
+
+    /// Render in the locale of a language tag, taken from a registry of locales, rather than
+    /// the built-in en-US locale.
+    ///
+    /// With the `per-item` localization scope, each reference with a `language` is rendered in
+    /// the locale of its language from the registry instead.
+    pub fn with_locales(mut self, locales: &'a Locales, tag: &str) -> Self {
+        self.locale = locales.locale(tag);
+        self.locales = Some(locales);
+        self.hints = OnceCell::new();
+        self
+    }
+
+    /// Write citations and bibliographies in an output format rather than plain text.
+    pub fn with_output(mut self, output: &'a dyn OutputFormat) -> Self {
+        self.output = output;
+        self
+    }
+
+    /// The disambiguation hints of the cited references, worked out on first use.
+    fn hints(&self) -> &HashMap<String, Hints> {
+        self.hints.get_or_init(|| self.disambiguate())
+    }
+
+    /// The context in which to render a reference.
+    fn context(
+        &self,
+        reference: &'a InputReference,
+        options: Vec<&'a OptionGroup>,
+    ) -> RenderContext<'a> {
+        let mut ctx = RenderContext::new(reference, options);
+        let scope = ctx.option(|o| o.localization.as_ref()?.scope.as_ref());
+        ctx.locale = match (scope, self.locales, &reference.language) {
+            (Some(Scope::PerItem), Some(locales), Some(language)) => locales.locale(language),
+            _ => self.locale,
+        };
+        ctx
+    }
+
+    /// Render every reference in the bibliography with the style bibliography template.
+    ///
+    /// Entries are ordered per the bibliography `sort` option.
+    pub fn render_bibliography(&self) -> Vec<ProcEntry> {
+        let template = match self
+            .style
+            .bibliography
+            .as_ref()
+            .and_then(|b| b.template.as_ref())
+        {
+            Some(template) => template,
+            None => return Vec::new(),
+        };
+        let options = self.bibliography_options();
+        self.sorted_ids()
+            .into_iter()
+            .filter_map(|id| {
+                let mut ctx = self.context(&self.bibliography[id], options.clone());
+                ctx.hints.year_suffix = self.hints().get(id).and_then(|h| h.year_suffix.clone());
+                let value = self.render_entry(template, &ctx)?;
+                Some(ProcEntry {
+                    id: id.clone(),
+                    value,
+                })
+            })
+            .collect()
+    }
+
+    /// The style and bibliography option groups.
+    fn bibliography_options(&self) -> Vec<&'a OptionGroup> {
+        let mut options: Vec<&OptionGroup> = self.style.options.iter().collect();
+        if let Some(bibliography) = &self.style.bibliography {
+            options.extend(&bibliography.options);
+        }
+        options
+    }
+
+    /// Render a single reference as a bibliography entry, ending with a period and with its
+    /// punctuation cleaned up per the locale, marked up as an entry of the output format, with
+    /// a hanging indent for the `hanging-indent` list style.
+    pub fn render_entry<'r>(
+        &self,
+        template: &'r [TemplateComponent],
+        ctx: &RenderContext<'r>,
+    ) -> Option<String> {
+        let value = self.render_template(template, ctx, ENTRY_DELIMITER)?;
+        let value = punctuation::punctuate(&value, ".", &ctx.locale, self.output);
+        let list_style = self
+            .style
+            .bibliography
+            .as_ref()
+            .and_then(|b| b.list_style.as_deref());
+        Some(
+            self.output
+                .entry(&value, list_style == Some(HANGING_INDENT)),
+        )
+    }
+
+    /// Render a list of template components, joining the non-empty results with the delimiter.
+    pub fn render_template<'r>(
+        &self,
+        template: &'r [TemplateComponent],
+        ctx: &RenderContext<'r>,
+        delimiter: &str,
+    ) -> Option<String> {
+        let parts: Vec<String> = template
+            .iter()
+            .filter_map(|component| self.render_component(component, ctx))
+            .collect();
+        if parts.is_empty() {
+            None
+        } else {
+            Some(join(&parts, delimiter, &ctx.locale, self.output))
+        }
+    }
+
+    /// Render a single template component; `None` when there is nothing to render.
+    ///
+    /// The text of a component is transformed to its text case, escaped for the output
+    /// format, and marked up with emphasis and strong emphasis per the `emph` and `bold` flags
+    /// of the component, and of the contributor options for contributors, then wrapped.
+    /// Components rendering a variable or term are marked up with its class.
+    pub fn render_component<'r>(
+        &self,
+        component: &'r TemplateComponent,
+        ctx: &RenderContext<'r>,
+    ) -> Option<String> {
+        let ctx = &ctx.with_options(component.options.as_ref());
+        let value = if let Some(conditions) = &component.when {
+            self.render_conditional(component, conditions, ctx)?
+        } else if let Some(template) = &component.template {
+            self.render_template(template, ctx, TEMPLATE_DELIMITER)?
+        } else {
+            let mut value = render_text(component, ctx)?;
+            if let Some(case) = &component.text_case {
+                let language = ctx.reference.language.as_deref();
+                let english = condition::is_language(language.unwrap_or(ctx.locale.tag()), "en");
+                value = text_case::text_case(&value, case, english);
+            }
+            self.output
+                .text(&punctuation::curl_quotes(&value, &ctx.locale))
+        };
+        if value.is_empty() {
+            return None;
+        }
+
+        let contributors = match component.contributor {
+            Some(_) => ctx.option(|o| o.contributors.as_ref()),
+            None => None,
+        };
+        let mut value = value;
+        if component.emph == Some(true) || contributors.and_then(|o| o.emph) == Some(true) {
+            value = self.output.emph(&value);
+        }
+        if component.bold == Some(true) || contributors.and_then(|o| o.bold) == Some(true) {
+            value = self.output.bold(&value);
+        }
+        let value = wrap(value, component.wrap.as_ref(), &ctx.locale);
+        Some(match class(component) {
+            Some(class) => self.output.component(&value, class),
+            None => value,
+        })
+    }
+
+    /// Render the template of the first condition that holds, or else the `else` template.
+    fn render_conditional<'r>(
+        &self,
+        component: &'r TemplateComponent,
+        conditions: &'r [Condition],
+        ctx: &RenderContext<'r>,
+    ) -> Option<String> {
+        let template = match conditions.iter().find(|c| condition::matches(c, ctx)) {
+            Some(condition) => condition.template.as_ref(),
+            None => component
+                .template_component_else
+                .as_ref()?
+                .template
+                .as_ref(),
+        }?;
+        self.render_template(template, ctx, TEMPLATE_DELIMITER)
+    }
+}
+
+/// The text of a component rendering a variable, term or text.
+fn render_text(component: &TemplateComponent, ctx: &RenderContext) -> Option<String> {
+    let reference = ctx.reference;
+    if let Some(role) = &component.contributor {
+        contributor::render_contributor(role, component.format.as_ref(), ctx)
+    } else if let Some(date) = &component.date {
+        render_date(date, component.format.as_ref(), ctx)
+    } else if let Some(title) = &component.title {
+        match title {
+            Titles::Title if substitute::is_substituted(ctx, &Substitute::Title) => None,
+            _ => render_title(title, component.format.as_ref(), ctx),
+        }
+    } else if let Some(variable) = &component.variable {
+        render_variable(variable, reference)
+    } else if let Some(locator) = &component.locator {
+        ctx.cite
+            .and_then(|cite| citation::locator_value(cite, locator))
+            .map(punctuation::range_dashes)
+    } else if let Some(term) = &component.term {
+        let format = match component.format {
+            Some(Format::Short) => Some(LocalizedTermFormat::Short),
+            Some(Format::Symbol) => Some(LocalizedTermFormat::Symbol),
+            _ => None,
+        };
+        Some(ctx.locale.term(term, format, false).to_string())
+    } else {
+        component.text.clone()
+    }
+}
+
+/// The class of what a component renders, for the markup of output formats.
+fn class(component: &TemplateComponent) -> Option<&'static str> {
+    if let Some(role) = &component.contributor {
+        Some(match role {
+            ContributorRoles::Author => "author",
+            ContributorRoles::CollectionEditor => "collection-editor",
+            ContributorRoles::Composer => "composer",
+            ContributorRoles::ContainerAuthor => "container-author",
+            ContributorRoles::Director => "director",
+            ContributorRoles::Editor => "editor",
+            ContributorRoles::Illustrator => "illustrator",
+            ContributorRoles::Interviewer => "interviewer",
+            ContributorRoles::Publisher => "publisher",
+            ContributorRoles::Recipient => "recipient",
+            ContributorRoles::Translator => "translator",
+        })
+    } else if let Some(date) = &component.date {
+        Some(match date {
+            Dates::Accessed => "accessed",
+            Dates::Issued => "issued",
+        })
+    } else if let Some(title) = &component.title {
+        Some(match title {
+            Titles::ContainerTitle => "container-title",
+            Titles::Title => "title",
+        })
+    } else if let Some(variable) = &component.variable {
+        Some(match variable {
+            SimpleTypes::Abstract => "abstract",
+            SimpleTypes::Archive => "archive",
+            SimpleTypes::Doi => "doi",
+            SimpleTypes::Edition => "edition",
+            SimpleTypes::Genre => "genre",
+            SimpleTypes::Isbn => "isbn",
+            SimpleTypes::Issn => "issn",
+            SimpleTypes::Issue => "issue",
+            SimpleTypes::Medium => "medium",
+            SimpleTypes::Note => "note",
+            SimpleTypes::Pages => "pages",
+            SimpleTypes::PublisherPlace => "publisher-place",
+            SimpleTypes::Url => "url",
+            SimpleTypes::Volume => "volume",
+        })
+    } else if component.locator.is_some() {
+        Some("locator")
+    } else if component.term.is_some() {
+        Some("term")
+    } else {
+        None
+    }
+}
+
+/// Render a date per the date options, with the year suffix of the reference after the date
+/// of issue.
+///
+/// Dates that are not EDTF are rendered as given.
+fn render_date(date: &Dates, format: Option<&Format>, ctx: &RenderContext) -> Option<String> {
+    let reference = ctx.reference;
+    let value = match date {
+        Dates::Accessed => reference.accessed.as_ref(),
+        Dates::Issued => reference.issued.as_ref(),
+    }?;
+    let mut value = match value.parse::<Edtf>() {
+        Ok(edtf) => date::format_date(&edtf, ctx.option(|o| o.dates.as_ref()), format, &ctx.locale),
+        Err(_) => value.clone(),
+    };
+    if let (Dates::Issued, Some(suffix)) = (date, &ctx.hints.year_suffix) {
+        value.push_str(suffix);
+    }
+    Some(value)
+}
+
+/// The value of a simple variable, with en dashes in page ranges.
+fn render_variable(variable: &SimpleTypes, reference: &InputReference) -> Option<String> {
+    let text = |value: &Option<String>| value.clone();
+    let number = |value: &Option<NumberOrString>| value.as_ref().map(ToString::to_string);
+    match variable {
+        SimpleTypes::Abstract => text(&reference.input_reference_abstract),
+        SimpleTypes::Archive => text(&reference.archive),
+        SimpleTypes::Doi => text(&reference.doi),
+        SimpleTypes::Edition => number(&reference.edition),
+        SimpleTypes::Genre => text(&reference.genre),
+        SimpleTypes::Isbn => text(&reference.isbn),
+        SimpleTypes::Issn => text(&reference.issn),
+        SimpleTypes::Issue => number(&reference.issue),
+        SimpleTypes::Medium => text(&reference.medium),
+        SimpleTypes::Note => text(&reference.note),
+        SimpleTypes::Pages => {
+            number(&reference.pages).map(|pages| punctuation::range_dashes(&pages))
+        }
+        SimpleTypes::PublisherPlace => text(&reference.publisher_place),
+        SimpleTypes::Url => text(&reference.url),
+        SimpleTypes::Volume => number(&reference.volume),
+    }
+}
+
+/// Wrap a rendered value in the symbol pair, or in the quotation marks of the locale.
+fn wrap(value: String, punctuation: Option<&WrapPunctuation>, locale: &Locale) -> String {
+    match punctuation {
+        None => value,
+        Some(WrapPunctuation::Parentheses) => format!("({})", value),
+        Some(WrapPunctuation::Brackets) => format!("[{}]", value),
+        Some(WrapPunctuation::Quotes) => punctuation::quote(&value, locale),
+    }
+}
+
+/// Join rendered parts with a delimiter, its leading punctuation cleaned up against the end of
+/// the preceding part.
+fn join(parts: &[String], delimiter: &str, locale: &Locale, output: &dyn OutputFormat) -> String {
+    let mut result = String::new();
+    for (index, part) in parts.iter().enumerate() {
+        if index > 0 {
+            result = punctuation::punctuate(&result, delimiter, locale, output);
+        }
+        result.push_str(part);
+    }
+    result
+}
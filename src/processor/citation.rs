@@ -0,0 +1,217 @@
+use crate::citation::{Citation, CitationModeType, Locator, RecordLocatorTermsString};
+use crate::locale::Locale;
+use crate::style::{LocalizedTermName, Locators, OptionGroup, Placement, TemplateComponent};
+
+use super::condition::is_plural_number;
+use super::punctuation::range_dashes;
+use super::{join, CiteReference, Processor, ProcessorError};
+
+/// The string with which to join the components of a non-integral citation.
+pub(super) const NON_INTEGRAL_DELIMITER: &str = ", ";
+
+/// The string with which to join the components of an integral citation.
+const INTEGRAL_DELIMITER: &str = " ";
+
+/// The string with which to join the rendered references of a citation.
+const CITE_DELIMITER: &str = "; ";
+
+impl<'a> Processor<'a> {
+    /// Render a citation, looking up each cited reference in the bibliography.
+    ///
+    /// The template is taken from the `integral` or `nonIntegral` citation specification
+    /// according to the citation mode, falling back to the citation `template`. Non-integral
+    /// citations with inline placement are wrapped in parentheses. The citation is then marked
+    /// up as a citation of the output format.
+    pub fn render_citation(&self, citation: &Citation) -> Result<String, ProcessorError> {
+        let integral = matches!(citation.mode, Some(CitationModeType::Integral));
+        let template = match self.citation_template(integral) {
+            Some(template) => template,
+            None => return Ok(String::new()),
+        };
+        let delimiter = if integral {
+            INTEGRAL_DELIMITER
+        } else {
+            NON_INTEGRAL_DELIMITER
+        };
+
+        let options = self.citation_options(integral);
+        let mut cites = Vec::new();
+        for cite in citation.references.iter().flatten() {
+            if let Some(value) = self.render_cite(cite, template, &options, delimiter)? {
+                cites.push(value);
+            }
+        }
+        if cites.is_empty() {
+            return Ok(String::new());
+        }
+
+        let mut parts = Vec::new();
+        let text = |affix: &String| self.output.text(affix);
+        parts.extend(citation.prefix.iter().filter(|p| !p.is_empty()).map(text));
+        parts.push(join(&cites, CITE_DELIMITER, &self.locale, self.output));
+        parts.extend(citation.suffix.iter().filter(|s| !s.is_empty()).map(text));
+        let value = parts.join(" ");
+
+        let placement = self
+            .style
+            .citation
+            .as_ref()
+            .and_then(|c| c.placement.as_ref());
+        let value = if integral || matches!(placement, Some(Placement::Note)) {
+            value
+        } else {
+            format!("({})", value)
+        };
+        Ok(self.output.citation(&value))
+    }
+
+    pub(super) fn citation_template(&self, integral: bool) -> Option<&'a [TemplateComponent]> {
+        let citation = self.style.citation.as_ref()?;
+        let list = if integral {
+            citation.integral.as_ref()
+        } else {
+            citation.non_integral.as_ref()
+        };
+        list.and_then(|l| l.template.as_deref())
+            .or(citation.template.as_deref())
+    }
+
+    /// The style, citation and citation mode option groups.
+    pub(super) fn citation_options(&self, integral: bool) -> Vec<&'a OptionGroup> {
+        let mut options: Vec<&OptionGroup> = self.style.options.iter().collect();
+        if let Some(citation) = &self.style.citation {
+            options.extend(&citation.options);
+            let list = if integral {
+                citation.integral.as_ref()
+            } else {
+                citation.non_integral.as_ref()
+            };
+            options.extend(list.and_then(|l| l.options.as_ref()));
+        }
+        options
+    }
+
+    /// Render one cited reference with its prefix and any locators not consumed by the
+    /// template.
+    fn render_cite(
+        &self,
+        cite: &CiteReference,
+        template: &[TemplateComponent],
+        options: &[&OptionGroup],
+        delimiter: &str,
+    ) -> Result<Option<String>, ProcessorError> {
+        let id = cite
+            .ref_id
+            .as_deref()
+            .ok_or(ProcessorError::MissingReferenceId)?;
+        let reference = self
+            .bibliography
+            .get(id)
+            .ok_or_else(|| ProcessorError::UnknownReference(id.to_string()))?;
+        let mut ctx = self.context(reference, options.to_vec());
+        ctx.cite = Some(cite);
+        ctx.hints = self.hints().get(id).cloned().unwrap_or_default();
+        let value = match self.render_template(template, &ctx, delimiter) {
+            Some(value) => value,
+            None => return Ok(None),
+        };
+
+        let mut parts = Vec::new();
+        parts.extend(
+            cite.prefix
+                .iter()
+                .filter(|p| !p.is_empty())
+                .map(|prefix| self.output.text(prefix)),
+        );
+        parts.push(value);
+        let mut value = parts.join(" ");
+        if !uses_locator(template) {
+            if let Some(locators) = render_locators(cite, &ctx.locale) {
+                let locators = self.output.text(&locators);
+                value = join(
+                    &[value, locators],
+                    NON_INTEGRAL_DELIMITER,
+                    &ctx.locale,
+                    self.output,
+                );
+            }
+        }
+        Ok(Some(value))
+    }
+}
+
+/// The value of a locator in the suffix of a citation reference.
+pub(super) fn locator_value<'r>(cite: &'r CiteReference, locator: &Locators) -> Option<&'r str> {
+    cite.suffix
+        .as_ref()?
+        .iter()
+        .find_map(|suffix| match suffix {
+            Locator::RecordLocatorTermsString(record) => match locator {
+                Locators::Chapter => record.chapter.as_deref(),
+                Locators::Page => record.page.as_deref(),
+            },
+            Locator::String(_) => None,
+        })
+}
+
+/// Whether a template renders locators itself.
+fn uses_locator(template: &[TemplateComponent]) -> bool {
+    template.iter().any(|component| {
+        component.locator.is_some() || component.template.as_deref().is_some_and(uses_locator)
+    })
+}
+
+/// Render the suffix of a citation reference as labelled locators, with en dashes in ranges.
+fn render_locators(cite: &CiteReference, locale: &Locale) -> Option<String> {
+    let parts: Vec<String> = cite
+        .suffix
+        .iter()
+        .flatten()
+        .flat_map(|suffix| match suffix {
+            Locator::RecordLocatorTermsString(record) => labelled_locators(record, locale),
+            Locator::String(value) => vec![range_dashes(value)],
+        })
+        .filter(|part| !part.is_empty())
+        .collect();
+    if parts.is_empty() {
+        None
+    } else {
+        Some(parts.join(NON_INTEGRAL_DELIMITER))
+    }
+}
+
+/// The locators of a record, labelled with their terms: in the plural for ranges and lists of
+/// numbers, such as "pages 12–14".
+fn labelled_locators(record: &RecordLocatorTermsString, locale: &Locale) -> Vec<String> {
+    let locators = [
+        (Some(LocalizedTermName::Book), &record.book),
+        (Some(LocalizedTermName::Chapter), &record.chapter),
+        (Some(LocalizedTermName::Column), &record.column),
+        (Some(LocalizedTermName::Figure), &record.figure),
+        (Some(LocalizedTermName::Folio), &record.folio),
+        (Some(LocalizedTermName::Line), &record.line),
+        (Some(LocalizedTermName::Note), &record.note),
+        (None, &record.number),
+        (Some(LocalizedTermName::Opus), &record.opus),
+        (Some(LocalizedTermName::Page), &record.page),
+        (Some(LocalizedTermName::Paragraph), &record.paragraph),
+        (Some(LocalizedTermName::Part), &record.part),
+        (Some(LocalizedTermName::Section), &record.section),
+        (Some(LocalizedTermName::SubVerbo), &record.sub_verbo),
+        (Some(LocalizedTermName::Verse), &record.verse),
+        (Some(LocalizedTermName::Volume), &record.volume),
+    ];
+    locators
+        .iter()
+        .filter_map(|(term, value)| {
+            let value = value.as_ref()?;
+            Some(match term {
+                Some(term) => {
+                    let label = locale.term(term, None, is_plural_number(value));
+                    format!("{} {}", label, range_dashes(value))
+                }
+                None => range_dashes(value),
+            })
+        })
+        .collect()
+}
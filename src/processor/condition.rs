@@ -0,0 +1,119 @@
+use crate::bibliography::date::Edtf;
+use crate::style::{
+    Condition, ContributorRoles, Dates, Match, ReferenceTypes, SimpleTypes, Variables,
+};
+
+use super::citation::locator_value;
+use super::contributor::names_in_role;
+use super::{render_variable, RenderContext};
+
+/// Whether a condition holds for the reference being rendered.
+///
+/// Every test present in the condition is evaluated separately, with each listed variable of
+/// `hasVariable` counting as its own test, and the results are combined according to `match`
+/// (`all` by default). A condition without tests never holds. The `locale` test holds when the
+/// language of the reference, or else of the locale being rendered in, is the tag or one of
+/// its regional variants.
+pub fn matches(condition: &Condition, ctx: &RenderContext) -> bool {
+    let mut tests = Vec::new();
+    if let Some(locator) = &condition.is_number {
+        let value = ctx.cite.and_then(|cite| locator_value(cite, locator));
+        tests.push(value.is_some_and(is_numeric));
+    }
+    if let Some(date) = &condition.is_edtf_date {
+        tests.push(date_value(date, ctx).is_some_and(|value| value.parse::<Edtf>().is_ok()));
+    }
+    if let Some(types) = &condition.is_ref_type {
+        let ref_type = ctx.reference.input_reference_type.as_ref();
+        tests.push(ref_type.is_some_and(|t| types.contains(t)));
+    }
+    for variable in condition.has_variable.iter().flatten() {
+        tests.push(has_variable(variable, ctx));
+    }
+    if let Some(tag) = &condition.locale {
+        let language = ctx.reference.language.as_deref();
+        tests.push(is_language(language.unwrap_or(ctx.locale.tag()), tag));
+    }
+    if tests.is_empty() {
+        return false;
+    }
+    match condition.condition_match.as_ref().unwrap_or(&Match::All) {
+        Match::All => tests.iter().all(|t| *t),
+        Match::Any => tests.iter().any(|t| *t),
+        Match::None => !tests.iter().any(|t| *t),
+    }
+}
+
+fn date_value<'r>(date: &Dates, ctx: &RenderContext<'r>) -> Option<&'r str> {
+    match date {
+        Dates::Accessed => ctx.reference.accessed.as_deref(),
+        Dates::Issued => ctx.reference.issued.as_deref(),
+    }
+}
+
+fn has_variable(variable: &Variables, ctx: &RenderContext) -> bool {
+    let reference = ctx.reference;
+    let ref_type = reference.input_reference_type.as_ref();
+    let names = |role| names_in_role(reference, &role).is_some();
+    let simple = |variable| render_variable(&variable, reference).is_some();
+    match variable {
+        Variables::Accessed => reference.accessed.is_some(),
+        Variables::Author => names(ContributorRoles::Author),
+        Variables::CollectionEditor => names(ContributorRoles::CollectionEditor),
+        Variables::Composer => names(ContributorRoles::Composer),
+        Variables::ContainerAuthor => names(ContributorRoles::ContainerAuthor),
+        Variables::Director => names(ContributorRoles::Director),
+        Variables::Editor => names(ContributorRoles::Editor),
+        Variables::Illustrator => names(ContributorRoles::Illustrator),
+        Variables::Interviewer => names(ContributorRoles::Interviewer),
+        Variables::Recipient => names(ContributorRoles::Recipient),
+        Variables::Translator => names(ContributorRoles::Translator),
+        Variables::ContainerTitle => reference.container_title.is_some(),
+        Variables::Issued => reference.issued.is_some(),
+        Variables::Language => reference.language.is_some(),
+        Variables::Publisher => reference.publisher.is_some(),
+        Variables::Title => reference.title.is_some(),
+        Variables::Abstract => simple(SimpleTypes::Abstract),
+        Variables::Archive => simple(SimpleTypes::Archive),
+        Variables::Doi => simple(SimpleTypes::Doi),
+        Variables::Edition => simple(SimpleTypes::Edition),
+        Variables::Genre => simple(SimpleTypes::Genre),
+        Variables::Isbn => simple(SimpleTypes::Isbn),
+        Variables::Issn => simple(SimpleTypes::Issn),
+        Variables::Issue => simple(SimpleTypes::Issue),
+        Variables::Medium => simple(SimpleTypes::Medium),
+        Variables::Note => simple(SimpleTypes::Note),
+        Variables::Pages => simple(SimpleTypes::Pages),
+        Variables::PublisherPlace => simple(SimpleTypes::PublisherPlace),
+        Variables::Url => simple(SimpleTypes::Url),
+        Variables::Volume => simple(SimpleTypes::Volume),
+        // These name reference types rather than variables; they hold for references of
+        // that type.
+        Variables::Article => ref_type == Some(&ReferenceTypes::Article),
+        Variables::Book => ref_type == Some(&ReferenceTypes::Book),
+        Variables::Chapter => ref_type == Some(&ReferenceTypes::Chapter),
+    }
+}
+
+/// Whether a language tag is that of a language or one of its regional variants: "de-AT" is
+/// "de" and "de-AT", but not "de-DE".
+pub(super) fn is_language(language: &str, tag: &str) -> bool {
+    let (language, tag) = (language.to_lowercase(), tag.to_lowercase());
+    language == tag || language.starts_with(&format!("{}-", tag))
+}
+
+/// The characters that join the numbers of a list or range.
+const NUMBER_SEPARATORS: [char; 4] = ['-', '–', ',', '&'];
+
+/// Whether a value is a number, or a list or range of numbers such as "12-14" or "2, 4".
+fn is_numeric(value: &str) -> bool {
+    value.chars().any(|c| c.is_ascii_digit())
+        && value
+            .chars()
+            .all(|c| c.is_ascii_digit() || c == ' ' || NUMBER_SEPARATORS.contains(&c))
+}
+
+/// Whether a value is a list or range of numbers rather than a single number.
+pub(super) fn is_plural_number(value: &str) -> bool {
+    is_numeric(value) && value.contains(NUMBER_SEPARATORS)
+}
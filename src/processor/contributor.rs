@@ -0,0 +1,420 @@
+use crate::bibliography::name::Name;
+use crate::bibliography::reference::{Contributor, InputReference};
+use crate::locale::{Locale, LocalizedTermFormat};
+use crate::style::{
+    AndAsString, AtAl, ContributorListFormatting, ContributorListShortening, ContributorRoles,
+    ContributorScope, DelimiterPrecedes, Form, Format, LocalizedTermName, RoleOption, Substitute,
+    Titles, WrapPunctuation,
+};
+
+use super::disambiguate::GivenNames;
+use super::substitute::{effective_author, is_substituted, AuthorValue};
+use super::{render_title, wrap, RenderContext};
+
+/// The string with which to join the names of a contributor list.
+const NAME_DELIMITER: &str = ", ";
+
+/// How much of a name to render.
+#[derive(Clone, Copy, PartialEq)]
+pub enum NameForm {
+    /// The family name only.
+    Family,
+    /// The family name with initials of the given names.
+    Initials,
+    /// The family and given names.
+    Full,
+}
+
+/// Adjustments to the rendering of a contributor list.
+#[derive(Clone, Copy)]
+pub struct NameList<'l> {
+    /// The form of names without disambiguation.
+    pub form: NameForm,
+    /// The names to expand for disambiguation.
+    pub given_names: GivenNames,
+    /// The least number of names to render before shortening with "et al.".
+    pub min_names: Option<usize>,
+    /// The locale of the "and" and "et al." terms.
+    pub locale: Locale<'l>,
+}
+
+impl Default for NameList<'_> {
+    fn default() -> Self {
+        NameList {
+            form: NameForm::Full,
+            given_names: GivenNames::default(),
+            min_names: None,
+            locale: Locale::default(),
+        }
+    }
+}
+
+impl NameList<'_> {
+    fn form(&self, index: usize) -> NameForm {
+        let expanded = match self.given_names {
+            GivenNames::None => None,
+            GivenNames::PrimaryInitials if index == 0 => Some(NameForm::Initials),
+            GivenNames::PrimaryFull if index == 0 => Some(NameForm::Full),
+            GivenNames::PrimaryInitials | GivenNames::PrimaryFull => None,
+            GivenNames::AllInitials => Some(NameForm::Initials),
+            GivenNames::AllFull => Some(NameForm::Full),
+        };
+        match (self.form, expanded) {
+            (NameForm::Full, _) | (_, None) => self.form,
+            (NameForm::Initials, Some(NameForm::Family)) => self.form,
+            (_, Some(expanded)) => expanded,
+        }
+    }
+}
+
+/// The names of the contributors of a reference in a role; `None` for publishers, which are
+/// not names.
+pub(super) fn names_in_role<'r>(
+    reference: &'r InputReference,
+    role: &ContributorRoles,
+) -> Option<&'r [Contributor]> {
+    let names = match role {
+        ContributorRoles::Author => &reference.author,
+        ContributorRoles::CollectionEditor => &reference.collection_editor,
+        ContributorRoles::Composer => &reference.composer,
+        ContributorRoles::ContainerAuthor => &reference.container_author,
+        ContributorRoles::Director => &reference.director,
+        ContributorRoles::Editor => &reference.editor,
+        ContributorRoles::Illustrator => &reference.illustrator,
+        ContributorRoles::Interviewer => &reference.interviewer,
+        ContributorRoles::Publisher => return None,
+        ContributorRoles::Recipient => &reference.recipient,
+        ContributorRoles::Translator => &reference.translator,
+    };
+    names.as_deref().filter(|names| !names.is_empty())
+}
+
+/// Render the contributors of a reference in a role, formatted per the contributor options.
+///
+/// With the `short` format, only family names are rendered, or the short title substituted
+/// for the author. Author names are expanded per the disambiguation hints of the context.
+pub(super) fn render_contributor(
+    role: &ContributorRoles,
+    format: Option<&Format>,
+    ctx: &RenderContext,
+) -> Option<String> {
+    let reference = ctx.reference;
+    let options = ctx.option(|o| o.contributors.as_ref());
+    let role_options = options.and_then(|o| o.role.as_ref());
+    let (names_role, contributors) = match role {
+        ContributorRoles::Author => match effective_author(ctx)? {
+            AuthorValue::Names(names_role, contributors) => (names_role, contributors),
+            AuthorValue::Title(_) if matches!(format, Some(Format::Short)) => {
+                return render_title(&Titles::Title, format, ctx)
+            }
+            AuthorValue::Title(title) => return Some(title),
+        },
+        ContributorRoles::Editor if is_substituted(ctx, &Substitute::Editor) => return None,
+        ContributorRoles::Translator if is_substituted(ctx, &Substitute::Translator) => {
+            return None
+        }
+        ContributorRoles::Publisher => {
+            let publisher = reference.publisher.clone()?;
+            return Some(label_role(
+                publisher,
+                role,
+                role,
+                false,
+                role_options,
+                &ctx.locale,
+            ));
+        }
+        _ => (role.clone(), names_in_role(reference, role)?),
+    };
+    let mut list = NameList {
+        locale: ctx.locale,
+        ..NameList::default()
+    };
+    if let Some(Format::Short) = format {
+        list.form = NameForm::Family;
+    }
+    if *role == ContributorRoles::Author {
+        list.given_names = ctx.hints.given_names;
+        list.min_names = ctx.hints.min_names;
+    }
+    let names = format_name_list(contributors, options, &list)?;
+    Some(label_role(
+        names,
+        role,
+        &names_role,
+        contributors.len() > 1,
+        role_options,
+        &ctx.locale,
+    ))
+}
+
+/// Annotate rendered names with their role.
+///
+/// `slot` is the role the template asks for and `role` the role of the names, which differ
+/// when the names are substituted for another role; omission is decided on the slot, so that
+/// the default omission of authors extends to author substitutions.
+pub fn label_role(
+    names: String,
+    slot: &ContributorRoles,
+    role: &ContributorRoles,
+    plural: bool,
+    options: Option<&RoleOption>,
+    locale: &Locale,
+) -> String {
+    let options = match options {
+        Some(options) => options,
+        None => return names,
+    };
+    let omitted = match &options.omit {
+        Some(omit) => omit.contains(slot),
+        None => *slot == ContributorRoles::Author,
+    };
+    if omitted {
+        return names;
+    }
+    let form = options.form.as_ref().unwrap_or(&Form::Long);
+    let term = locale.role(role, form, plural);
+    match form {
+        Form::Long | Form::Short => {
+            let punctuation = options
+                .wrap
+                .as_ref()
+                .unwrap_or(&WrapPunctuation::Parentheses);
+            format!(
+                "{} {}",
+                names,
+                wrap(term.to_string(), Some(punctuation), locale)
+            )
+        }
+        Form::Verb | Form::VerbShort => format!("{} {}", term, names),
+    }
+}
+
+/// Format a list of contributor names with the built-in en-US terms.
+///
+/// Names are joined with the name delimiter and the "and" term or symbol, shortened with
+/// the "et al." or "and others" term, and displayed in sort order, as configured.
+pub fn format_names(
+    contributors: &[Contributor],
+    options: Option<&ContributorListFormatting>,
+) -> Option<String> {
+    format_name_list(contributors, options, &NameList::default())
+}
+
+/// Format a list of contributor names with rendering adjustments.
+pub fn format_name_list(
+    contributors: &[Contributor],
+    options: Option<&ContributorListFormatting>,
+    list: &NameList,
+) -> Option<String> {
+    if contributors.is_empty() {
+        return None;
+    }
+    let display_as_sort = options.and_then(|o| o.display_as_sort.as_ref());
+    let inverted = |index: usize| match display_as_sort {
+        Some(ContributorScope::All) => true,
+        Some(ContributorScope::First) => index == 0,
+        Some(ContributorScope::None) | None => false,
+    };
+    let initialize_with = options.and_then(|o| o.initialize_with.as_deref());
+    let initials = Initials {
+        with: initialize_with.unwrap_or(Initials::default().with),
+        hyphen: options
+            .and_then(|o| o.initialize_with_hyphen)
+            .unwrap_or(true),
+        digraphs: list.locale.initial_digraphs(),
+    };
+    let list = match (list.form, initialize_with) {
+        (NameForm::Full, Some(_)) => NameList {
+            form: NameForm::Initials,
+            ..*list
+        },
+        _ => *list,
+    };
+    let names: Vec<String> = contributors
+        .iter()
+        .enumerate()
+        .map(|(index, contributor)| {
+            format_name(
+                &contributor.structured_name(),
+                inverted(index),
+                list.form(index),
+                &initials,
+            )
+        })
+        .collect();
+    let shorten = options.and_then(|o| o.shorten.as_ref());
+
+    let keep = shorten
+        .and_then(|s| shortened_length(s, names.len()))
+        .map(|keep| keep.max(list.min_names.unwrap_or(0)))
+        .filter(|keep| *keep < names.len());
+    if let Some(keep) = keep {
+        let et_al = match shorten.and_then(|s| s.at_al.as_ref()) {
+            Some(AtAl::Long) => LocalizedTermName::AndOthers,
+            Some(AtAl::Short) | None => LocalizedTermName::EtAl,
+        };
+        let precedes = match shorten.and_then(|s| s.delimiter_precedes_et_al.as_ref()) {
+            Some(DelimiterPrecedes::AfterInvertedName) => inverted(keep - 1),
+            Some(DelimiterPrecedes::Always) => true,
+            Some(DelimiterPrecedes::Contextual) | None => keep >= 2,
+            Some(DelimiterPrecedes::Never) => false,
+        };
+        let separator = if precedes { NAME_DELIMITER } else { " " };
+        return Some(format!(
+            "{}{}{}",
+            names[..keep].join(NAME_DELIMITER),
+            separator,
+            list.locale.term(&et_al, None, false)
+        ));
+    }
+
+    let (last, rest) = names.split_last()?;
+    if rest.is_empty() {
+        return Some(last.clone());
+    }
+    let and = match options.and_then(|o| o.and_as.as_ref()) {
+        Some(AndAsString::Symbol) => Some(LocalizedTermFormat::Symbol),
+        Some(AndAsString::Text) | None => None,
+    };
+    let and = list.locale.term(&LocalizedTermName::And, and, false);
+    let precedes = match shorten.and_then(|s| s.delimiter_precedes_last.as_ref()) {
+        Some(DelimiterPrecedes::AfterInvertedName) => inverted(rest.len() - 1),
+        Some(DelimiterPrecedes::Always) => true,
+        Some(DelimiterPrecedes::Contextual) => names.len() >= 3,
+        Some(DelimiterPrecedes::Never) | None => false,
+    };
+    let separator = if precedes { NAME_DELIMITER } else { " " };
+    Some(format!(
+        "{}{}{} {}",
+        rest.join(NAME_DELIMITER),
+        separator,
+        and,
+        last
+    ))
+}
+
+/// The number of names to keep when a list of `len` names is shortened, if it is.
+fn shortened_length(shorten: &ContributorListShortening, len: usize) -> Option<usize> {
+    let use_first = shorten.use_first.map(|n| n as usize).unwrap_or(1).max(1);
+    let min = shorten.min.map(|n| n as usize);
+    let shorten = match min {
+        Some(min) => len >= min,
+        None => shorten.use_first.is_some(),
+    };
+    if shorten && use_first < len {
+        Some(use_first)
+    } else {
+        None
+    }
+}
+
+/// A contributor name in sort order.
+pub(super) fn sort_name(contributor: &Contributor) -> String {
+    format_name(
+        &contributor.structured_name(),
+        true,
+        NameForm::Full,
+        &Initials::default(),
+    )
+}
+
+/// Format a name in display ("Jan van der Berg Jr.") or sort ("van der Berg, Jan, Jr.")
+/// order.
+///
+/// The family form is the family name with its non-dropping particle only; dropping
+/// particles and suffixes go with the given names. Literal names are rendered as is, and
+/// CJK given names are not initialized.
+fn format_name(name: &Name, inverted: bool, form: NameForm, initials: &Initials) -> String {
+    if let Some(literal) = &name.literal {
+        return literal.clone();
+    }
+    let family = name.family_part().unwrap_or_default();
+    if form == NameForm::Family {
+        return family;
+    }
+    let given = name.given.as_deref().map(|given| match form {
+        NameForm::Initials if !given.chars().any(is_cjk) => initials.initialize(given),
+        NameForm::Initials | NameForm::Family | NameForm::Full => given.to_string(),
+    });
+    let given: Vec<String> = given
+        .into_iter()
+        .chain(name.dropping_particle.clone())
+        .collect();
+    let given = given.join(" ");
+    match (inverted, given.is_empty(), &name.suffix) {
+        (_, true, Some(suffix)) => format!("{} {}", family, suffix),
+        (_, true, None) => family,
+        (true, false, Some(suffix)) => format!("{}, {}, {}", family, given, suffix),
+        (true, false, None) => format!("{}, {}", family, given),
+        (false, false, Some(suffix)) => format!("{} {} {}", given, family, suffix),
+        (false, false, None) => format!("{} {}", given, family),
+    }
+}
+
+/// How given names are initialized.
+struct Initials<'a> {
+    /// The string after each initial.
+    with: &'a str,
+    /// Whether initials of the parts of a hyphenated name are joined with a hyphen.
+    hyphen: bool,
+    /// The letter groups initialized as one letter, from the locale.
+    digraphs: &'a [String],
+}
+
+impl Default for Initials<'_> {
+    fn default() -> Self {
+        Initials {
+            with: ". ",
+            hyphen: true,
+            digraphs: &[],
+        }
+    }
+}
+
+impl Initials<'_> {
+    /// The initials of given names: "Jane Ann" becomes "J. A.", "Jean-Paul" "J.-P." and
+    /// "J.R.R." "J. R. R.". Whitespace after the last initial is trimmed.
+    fn initialize(&self, given: &str) -> String {
+        let mut result = String::new();
+        let words = given.split(|c: char| c.is_whitespace() || c == '.');
+        for word in words.filter(|word| !word.is_empty()) {
+            let parts = word.split('-').enumerate();
+            for (index, part) in parts.filter(|(_, part)| !part.is_empty()) {
+                if index > 0 && self.hyphen {
+                    result.truncate(result.trim_end().len());
+                    result.push('-');
+                }
+                result.push_str(self.initial(part));
+                result.push_str(self.with);
+            }
+        }
+        result.truncate(result.trim_end().len());
+        result
+    }
+
+    /// The initial of a name: its longest leading digraph, or else its first letter.
+    fn initial<'p>(&self, part: &'p str) -> &'p str {
+        let digraph = self
+            .digraphs
+            .iter()
+            .filter(|digraph| part.len() > digraph.len() && part.starts_with(digraph.as_str()))
+            .map(String::len)
+            .max();
+        let end = digraph.unwrap_or_else(|| part.chars().next().map_or(0, char::len_utf8));
+        &part[..end]
+    }
+}
+
+/// Whether a character is Chinese, Japanese or Korean; such names are not initialized.
+fn is_cjk(c: char) -> bool {
+    matches!(
+        c,
+        '\u{1100}'..='\u{11FF}'
+            | '\u{3040}'..='\u{30FF}'
+            | '\u{3400}'..='\u{4DBF}'
+            | '\u{4E00}'..='\u{9FFF}'
+            | '\u{AC00}'..='\u{D7AF}'
+            | '\u{F900}'..='\u{FAFF}'
+    )
+}
@@ -0,0 +1,311 @@
+use crate::bibliography::date::{Bound, Date, Edtf, Time};
+use crate::locale::Locale;
+use crate::style::{DateFormatting, EStyle, Format, LocalizedTermName, MonthStyle, YearStyle};
+
+/// The string with which to join the ends of a date range.
+const RANGE_DELIMITER: &str = "–";
+
+/// The parts of a date to render.
+#[derive(Clone, Copy)]
+struct Parts {
+    year: bool,
+    month: bool,
+    day: bool,
+}
+
+/// Render a parsed date per the date options and the component format.
+///
+/// The component format selects the parts of the date: `year`, `year-month`, `month-day`,
+/// or `full` (the default) for all of them. The `date` option sets the month style, long
+/// by default, which the `month` option overrides; the `time` option adds the time of
+/// dates that have one. Dates and times are written per the date patterns of the locale.
+/// Intervals render as ranges, writing a shared year once ("May–June 2020"). Approximate
+/// dates are marked with the "circa" term and uncertain dates with a question mark.
+pub fn format_date(
+    edtf: &Edtf,
+    options: Option<&DateFormatting>,
+    format: Option<&Format>,
+    locale: &Locale,
+) -> String {
+    let parts = match format {
+        Some(Format::Year) => Parts {
+            year: true,
+            month: false,
+            day: false,
+        },
+        Some(Format::YearMonth) => Parts {
+            year: true,
+            month: true,
+            day: false,
+        },
+        Some(Format::MonthDay) => Parts {
+            year: false,
+            month: true,
+            day: true,
+        },
+        _ => Parts {
+            year: true,
+            month: true,
+            day: true,
+        },
+    };
+    let formatter = Formatter::new(options, *locale);
+    match edtf {
+        Edtf::Date(date) => formatter.date(date, parts),
+        Edtf::Interval(start, end) => formatter.range(start, end, parts),
+    }
+}
+
+struct Formatter<'o> {
+    locale: Locale<'o>,
+    month: &'o MonthStyle,
+    year: &'o YearStyle,
+    time: Option<&'o EStyle>,
+}
+
+impl<'o> Formatter<'o> {
+    fn new(options: Option<&'o DateFormatting>, locale: Locale<'o>) -> Self {
+        let month = options.and_then(|o| o.month.as_ref());
+        let month = month.unwrap_or(match options.and_then(|o| o.date.as_ref()) {
+            Some(EStyle::Full) | Some(EStyle::Long) | None => &MonthStyle::Long,
+            Some(EStyle::Medium) => &MonthStyle::Short,
+            Some(EStyle::Short) => &MonthStyle::Numeric,
+        });
+        Formatter {
+            locale,
+            month,
+            year: options
+                .and_then(|o| o.year.as_ref())
+                .unwrap_or(&YearStyle::Numeric),
+            time: options.and_then(|o| o.time.as_ref()),
+        }
+    }
+
+    fn numeric(&self) -> bool {
+        matches!(self.month, MonthStyle::Numeric | MonthStyle::The2Digit)
+    }
+
+    fn range(&self, start: &Bound, end: &Bound, parts: Parts) -> String {
+        let (start, end) = match (start.date(), end.date()) {
+            (Some(start), Some(end)) => (start, end),
+            (Some(start), None) => {
+                return format!("{}{}", self.date(start, parts), RANGE_DELIMITER)
+            }
+            (None, Some(end)) => return format!("{}{}", RANGE_DELIMITER, self.date(end, parts)),
+            (None, None) => return String::new(),
+        };
+        let marked = |date: &Date| date.approximate || date.uncertain;
+        let has_month = |date: &Date| date.month.is_some() || date.season.is_some();
+        let collapsible = !self.numeric()
+            && !marked(start)
+            && !marked(end)
+            && start.time.is_none()
+            && end.time.is_none()
+            && parts.year
+            && parts.month
+            && start.year == end.year
+            && has_month(start)
+            && has_month(end);
+        if !collapsible {
+            let start = self.date(start, parts);
+            let end = self.date(end, parts);
+            return if start == end {
+                start
+            } else {
+                format!("{}{}{}", start, RANGE_DELIMITER, end)
+            };
+        }
+
+        // The shared year is written once, on the side of the range where the date pattern
+        // of the locale puts it; days of the same month make a range of days, each with the
+        // text of the pattern attached to the day ("5.–7. Mai 2020").
+        if let (true, Some(month), Some(start_day), Some(end_day)) = (
+            parts.day && start.month == end.month,
+            start.month,
+            start.day,
+            end.day,
+        ) {
+            let days = format!(
+                "{}{}{}{}",
+                start_day,
+                self.day_suffix(),
+                RANGE_DELIMITER,
+                end_day
+            );
+            return self.fill(
+                Some(self.year_value(start)),
+                Some(self.month_value(month)),
+                Some(days),
+                false,
+            );
+        }
+        let without_year = Parts {
+            year: false,
+            ..parts
+        };
+        let pattern = self.locale.date_pattern(|p| p.date.as_ref());
+        let year_first = pattern.find("{year}") < pattern.find("{month}");
+        let (start, end) = if year_first {
+            (self.date(start, parts), self.date(end, without_year))
+        } else {
+            (self.date(start, without_year), self.date(end, parts))
+        };
+        format!("{}{}{}", start, RANGE_DELIMITER, end)
+    }
+
+    fn date(&self, date: &Date, parts: Parts) -> String {
+        let year = Some(self.year_value(date)).filter(|_| parts.year);
+        let month = if !parts.month {
+            None
+        } else if let Some(season) = &date.season {
+            Some(self.locale.season(season).to_string())
+        } else {
+            date.month.map(|month| self.month_value(month))
+        };
+        let day = date
+            .day
+            .filter(|_| parts.day && date.month.is_some())
+            .map(|day| match self.month {
+                MonthStyle::The2Digit => format!("{:02}", day),
+                _ => day.to_string(),
+            });
+
+        let mut value = self.fill(year, month, day, date.season.is_some());
+        if let (Some(style), Some(time)) = (self.time, &date.time) {
+            value = format!("{} {}", value, self.time_value(time, style));
+        }
+        if date.approximate {
+            let circa = self.locale.term(&LocalizedTermName::Circa, None, false);
+            value = format!("{} {}", circa, value);
+        }
+        if date.uncertain {
+            value.push('?');
+        }
+        value
+    }
+
+    /// Fill the date pattern of the locale for the parts present: textual, or numeric for
+    /// numeric months.
+    fn fill(
+        &self,
+        year: Option<String>,
+        month: Option<String>,
+        day: Option<String>,
+        season: bool,
+    ) -> String {
+        let numeric = self.numeric() && !season;
+        let pattern = match (&year, &month, &day) {
+            (Some(_), Some(_), Some(_)) if numeric => {
+                self.locale.date_pattern(|p| p.numeric_date.as_ref())
+            }
+            (Some(_), Some(_), Some(_)) => self.locale.date_pattern(|p| p.date.as_ref()),
+            (Some(_), Some(_), None) if numeric => {
+                self.locale.date_pattern(|p| p.numeric_year_month.as_ref())
+            }
+            (Some(_), Some(_), None) => self.locale.date_pattern(|p| p.year_month.as_ref()),
+            (None, Some(_), Some(_)) if numeric => {
+                self.locale.date_pattern(|p| p.numeric_month_day.as_ref())
+            }
+            (None, Some(_), Some(_)) => self.locale.date_pattern(|p| p.month_day.as_ref()),
+            (None, Some(_), None) => "{month}",
+            (Some(_), None, _) => self.locale.date_pattern(|p| p.year.as_ref()),
+            (None, None, _) => return String::new(),
+        };
+        pattern
+            .replace("{year}", year.as_deref().unwrap_or_default())
+            .replace("{month}", month.as_deref().unwrap_or_default())
+            .replace("{day}", day.as_deref().unwrap_or_default())
+    }
+
+    /// The year, with the "BC" term for years before 1 and the "AD" term for years before
+    /// 1000.
+    fn year_value(&self, date: &Date) -> String {
+        let mut digits = if date.year <= 0 {
+            (1 - date.year).to_string()
+        } else {
+            match self.year {
+                YearStyle::The2Digit if date.year >= 1000 => format!("{:02}", date.year % 100),
+                _ => date.year.to_string(),
+            }
+        };
+        let unspecified = usize::from(date.unspecified_digits).min(digits.len());
+        digits.truncate(digits.len() - unspecified);
+        digits.push_str(&"X".repeat(unspecified));
+        if date.year <= 0 {
+            format!(
+                "{} {}",
+                digits,
+                self.locale.term(&LocalizedTermName::Bc, None, false)
+            )
+        } else if date.year < 1000 {
+            format!(
+                "{} {}",
+                digits,
+                self.locale.term(&LocalizedTermName::Ad, None, false)
+            )
+        } else {
+            digits
+        }
+    }
+
+    fn month_value(&self, month: u8) -> String {
+        match self.month {
+            MonthStyle::Long => self.locale.month(month, false).to_string(),
+            MonthStyle::Short => self.locale.month(month, true).to_string(),
+            MonthStyle::Narrow => self.locale.month(month, false).chars().take(1).collect(),
+            MonthStyle::Numeric => month.to_string(),
+            MonthStyle::The2Digit => format!("{:02}", month),
+        }
+    }
+
+    /// The text attached to the day in the month-day pattern of the locale, up to a space or
+    /// another placeholder, such as the "." of "{day}. {month}".
+    fn day_suffix(&self) -> &'o str {
+        let pattern = self.locale.date_pattern(|p| p.month_day.as_ref());
+        let Some((_, after)) = pattern.split_once("{day}") else {
+            return "";
+        };
+        let end = after
+            .find(|c: char| c.is_whitespace() || c == '{')
+            .unwrap_or(after.len());
+        &after[..end]
+    }
+
+    /// A time of day per the time pattern of the locale, with seconds unless the style is
+    /// short, and the offset from UTC when the style is long or full. The period of 12-hour
+    /// times is the `am` or `pm` string of the locale.
+    fn time_value(&self, time: &Time, style: &EStyle) -> String {
+        let pattern = match style {
+            EStyle::Short => self.locale.date_pattern(|p| p.short_time.as_ref()),
+            _ => self.locale.date_pattern(|p| p.time.as_ref()),
+        };
+        let period = if time.hour < 12 {
+            self.locale.date_pattern(|p| p.am.as_ref())
+        } else {
+            self.locale.date_pattern(|p| p.pm.as_ref())
+        };
+        let hour = match time.hour % 12 {
+            0 => 12,
+            hour => hour,
+        };
+        let hour = if pattern.contains("{period}") {
+            hour.to_string()
+        } else {
+            format!("{:02}", time.hour)
+        };
+        let mut value = pattern
+            .replace("{hour}", &hour)
+            .replace("{minute}", &format!("{:02}", time.minute))
+            .replace("{second}", &format!("{:02}", time.second))
+            .replace("{period}", period);
+        if let (EStyle::Long | EStyle::Full, Some(offset)) = (style, time.offset) {
+            value.push_str(" UTC");
+            if offset != 0 {
+                let sign = if offset < 0 { '-' } else { '+' };
+                let offset = offset.abs();
+                value.push_str(&format!("{}{:02}:{:02}", sign, offset / 60, offset % 60));
+            }
+        }
+        value
+    }
+}
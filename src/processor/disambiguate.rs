@@ -0,0 +1,209 @@
+use std::collections::HashMap;
+
+use crate::style::{AddNames, OptionGroup, TemplateComponent};
+
+use super::citation::NON_INTEGRAL_DELIMITER;
+use super::substitute::{effective_author, AuthorValue};
+use super::Processor;
+
+/// The given names to render in addition to family names, to tell apart authors.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum GivenNames {
+    #[default]
+    None,
+    /// Initials of the first author.
+    PrimaryInitials,
+    /// The full given names of the first author.
+    PrimaryFull,
+    /// Initials of every author.
+    AllInitials,
+    /// The full given names of every author.
+    AllFull,
+}
+
+/// How the citations of a reference are rendered to tell them apart from others.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Hints {
+    /// The least number of author names to render before shortening with "et al.".
+    pub min_names: Option<usize>,
+    /// The author given names to render.
+    pub given_names: GivenNames,
+    /// The letter appended to the year of issue.
+    pub year_suffix: Option<String>,
+}
+
+impl<'a> Processor<'a> {
+    /// Work out the hints with which to render each cited reference so that no two render
+    /// the same non-integral citation.
+    ///
+    /// References rendering the same citation first get more author names, then the given
+    /// names or initials allowed by the `addNames` strategy, settling on the first that tells
+    /// the whole group apart, or else the one that tells most apart. References still
+    /// ambiguous get year suffixes ("2020a", "2020b") in bibliography order when
+    /// `addYearSuffix` is set. Without citations, every reference in the bibliography is
+    /// considered cited.
+    pub(super) fn disambiguate(&self) -> HashMap<String, Hints> {
+        let options = self.citation_options(false);
+        let settings = options.iter().rev().find_map(|o| o.disambiguate.as_ref());
+        let (settings, template) = match (settings, self.citation_template(false)) {
+            (Some(settings), Some(template)) => (settings, template),
+            _ => return HashMap::new(),
+        };
+        let cited = self.cited_order();
+        let ids: Vec<&String> = self
+            .sorted_ids()
+            .into_iter()
+            .filter(|id| cited.is_empty() || cited.contains_key(id.as_str()))
+            .collect();
+        let trial = Trial {
+            processor: self,
+            template,
+            options: &options,
+        };
+        let mut hints: HashMap<&String, Hints> =
+            ids.iter().map(|id| (*id, Hints::default())).collect();
+
+        if let Some(add_names) = &settings.add_names {
+            for group in trial.ambiguous(&ids, &hints) {
+                let mut best = (group.len(), Hints::default());
+                for candidate in self.name_candidates(&group, &options, add_names) {
+                    let mut trial_hints = hints.clone();
+                    for id in &group {
+                        trial_hints.insert(*id, candidate.clone());
+                    }
+                    let remaining: usize = trial
+                        .ambiguous(&group, &trial_hints)
+                        .iter()
+                        .map(Vec::len)
+                        .sum();
+                    if remaining < best.0 {
+                        best = (remaining, candidate);
+                    }
+                    if remaining == 0 {
+                        break;
+                    }
+                }
+                for id in &group {
+                    hints.insert(*id, best.1.clone());
+                }
+            }
+        }
+
+        if settings.add_year_suffix == Some(true) {
+            for group in trial.ambiguous(&ids, &hints) {
+                for (index, id) in group.iter().enumerate() {
+                    if let Some(hint) = hints.get_mut(id) {
+                        hint.year_suffix = Some(year_suffix(index));
+                    }
+                }
+            }
+        }
+
+        hints
+            .into_iter()
+            .filter(|(_, hint)| *hint != Hints::default())
+            .map(|(id, hint)| (id.clone(), hint))
+            .collect()
+    }
+
+    /// The hints to try in turn on a group of ambiguous references: more names, then given
+    /// names per the strategy, with as few names as possible.
+    fn name_candidates(
+        &self,
+        group: &[&String],
+        options: &[&'a OptionGroup],
+        add_names: &AddNames,
+    ) -> Vec<Hints> {
+        let most_names = group
+            .iter()
+            .filter_map(|id| {
+                let ctx = self.context(&self.bibliography[*id], options.to_vec());
+                match effective_author(&ctx)? {
+                    AuthorValue::Names(_, names) => Some(names.len()),
+                    AuthorValue::Title(_) => None,
+                }
+            })
+            .max()
+            .unwrap_or(0);
+        let given_names: &[GivenNames] = match add_names {
+            AddNames::All => &[GivenNames::AllFull],
+            AddNames::AllWithInitials => &[GivenNames::AllInitials],
+            AddNames::ByCite => &[GivenNames::AllInitials, GivenNames::AllFull],
+            AddNames::Primary => &[GivenNames::PrimaryFull],
+            AddNames::PrimaryWithInitials => &[GivenNames::PrimaryInitials],
+        };
+
+        let min_names = || (2..=most_names).map(Some);
+        let mut candidates: Vec<Hints> = min_names()
+            .map(|min_names| Hints {
+                min_names,
+                ..Hints::default()
+            })
+            .collect();
+        for given_names in given_names {
+            for min_names in std::iter::once(None).chain(min_names()) {
+                candidates.push(Hints {
+                    min_names,
+                    given_names: *given_names,
+                    year_suffix: None,
+                });
+            }
+        }
+        candidates
+    }
+}
+
+/// Trial renderings of the non-integral citation of references.
+struct Trial<'p, 'a> {
+    processor: &'p Processor<'a>,
+    template: &'a [TemplateComponent],
+    options: &'p [&'a OptionGroup],
+}
+
+impl<'p, 'a> Trial<'p, 'a> {
+    fn render(&self, id: &String, hints: &HashMap<&String, Hints>) -> Option<String> {
+        let mut ctx = self
+            .processor
+            .context(&self.processor.bibliography[id], self.options.to_vec());
+        ctx.hints = hints.get(id).cloned().unwrap_or_default();
+        self.processor
+            .render_template(self.template, &ctx, NON_INTEGRAL_DELIMITER)
+    }
+
+    /// The groups of references rendering the same citation, in order of first reference.
+    fn ambiguous<'i>(
+        &self,
+        ids: &[&'i String],
+        hints: &HashMap<&String, Hints>,
+    ) -> Vec<Vec<&'i String>> {
+        let mut groups: Vec<(String, Vec<&String>)> = Vec::new();
+        for id in ids {
+            let value = match self.render(id, hints) {
+                Some(value) => value,
+                None => continue,
+            };
+            match groups.iter_mut().find(|(rendered, _)| *rendered == value) {
+                Some((_, group)) => group.push(id),
+                None => groups.push((value, vec![id])),
+            }
+        }
+        groups
+            .into_iter()
+            .map(|(_, group)| group)
+            .filter(|group| group.len() > 1)
+            .collect()
+    }
+}
+
+/// The year suffix at a position: "a" to "z", then "aa", "ab" and so on.
+fn year_suffix(index: usize) -> String {
+    let mut suffix = Vec::new();
+    let mut n = index + 1;
+    while n > 0 {
+        n -= 1;
+        suffix.push(b'a' + (n % 26) as u8);
+        n /= 26;
+    }
+    suffix.reverse();
+    String::from_utf8(suffix).unwrap_or_default()
+}
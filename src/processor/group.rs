@@ -0,0 +1,162 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+use crate::bibliography::date::{Date, Edtf};
+use crate::style::{
+    ContributorRoles, Format, Group, GroupSortKeys, LocalizedTermName, SortConfig, Titles,
+};
+
+use super::contributor::render_contributor;
+use super::date::format_date;
+use super::sort::{compare, sort_value, year, SortValue};
+use super::{render_title, ProcEntry, Processor, RenderContext};
+
+/// The string with which to join the values of a group heading, unless the key gives one.
+const HEADING_DELIMITER: &str = ", ";
+
+/// A group of bibliography entries sharing the values of the grouping keys.
+pub struct ProcGroup {
+    /// The values of the grouping keys; `None` when the bibliography is not grouped.
+    pub heading: Option<String>,
+    pub entries: Vec<ProcEntry>,
+}
+
+impl<'a> Processor<'a> {
+    /// Render the bibliography in groups per the bibliography `group` option.
+    ///
+    /// Groups are ordered on the values of their keys in turn, in the order of the `sort`
+    /// option for the same key, else ascending, with groups missing a value last; entries keep
+    /// their bibliography order within a group. Each heading value is followed by the delimiter
+    /// of its key, ", " by default, when another comes after it. The `as-cited` key does not
+    /// define groups and is ignored.
+    pub fn render_grouped_bibliography(&self) -> Vec<ProcGroup> {
+        let entries = self.render_bibliography();
+        let options = self.bibliography_options();
+        let groups = match options.iter().rev().find_map(|o| o.group.as_ref()) {
+            Some(groups) if !groups.is_empty() => groups,
+            _ => {
+                return vec![ProcGroup {
+                    heading: None,
+                    entries,
+                }]
+            }
+        };
+        let sort = options.iter().rev().find_map(|o| o.sort.as_ref());
+        let configs: Vec<SortConfig> = groups
+            .iter()
+            .map(|group| {
+                let key = group.key();
+                sort.into_iter()
+                    .flatten()
+                    .find(|config| config.key.as_ref() == Some(key))
+                    .cloned()
+                    .unwrap_or(SortConfig {
+                        key: Some(key.clone()),
+                        order: None,
+                    })
+            })
+            .collect();
+        let cited = self.cited_order();
+
+        let mut keyed: Vec<(Vec<SortValue>, ProcGroup)> = Vec::new();
+        for entry in entries {
+            let ctx = self.context(&self.bibliography[&entry.id], options.clone());
+            let heading = Some(heading(groups, &ctx));
+            match keyed.iter_mut().find(|(_, group)| group.heading == heading) {
+                Some((_, group)) => group.entries.push(entry),
+                None => {
+                    let values = configs
+                        .iter()
+                        .map(|config| group_sort_value(config, &ctx, &entry.id, &cited))
+                        .collect();
+                    keyed.push((
+                        values,
+                        ProcGroup {
+                            heading,
+                            entries: vec![entry],
+                        },
+                    ));
+                }
+            }
+        }
+        keyed.sort_by(|(a, _), (b, _)| {
+            configs
+                .iter()
+                .zip(a.iter().zip(b.iter()))
+                .map(|(config, (a, b))| compare(config, a, b))
+                .find(|ordering| *ordering != Ordering::Equal)
+                .unwrap_or(Ordering::Equal)
+        });
+        keyed.into_iter().map(|(_, group)| group).collect()
+    }
+}
+
+/// The heading of the group of a reference: the values of its grouping keys, each followed
+/// by the delimiter of its key when another value comes after it.
+fn heading(groups: &[Group], ctx: &RenderContext) -> String {
+    let mut heading = String::new();
+    let mut delimiter = None;
+    for group in groups {
+        if let Some(value) = group_value(group.key(), ctx) {
+            if let Some(delimiter) = delimiter {
+                heading.push_str(delimiter);
+            }
+            heading.push_str(&value);
+            delimiter = Some(group.delimiter().unwrap_or(HEADING_DELIMITER));
+        }
+    }
+    heading
+}
+
+/// The value on which to order groups for a grouping key: the year of issue alone for the
+/// `year` key, and else the sort value.
+fn group_sort_value(
+    config: &SortConfig,
+    ctx: &RenderContext,
+    id: &str,
+    cited: &HashMap<&str, usize>,
+) -> SortValue {
+    match config.key {
+        Some(GroupSortKeys::Year) => ctx
+            .reference
+            .issued
+            .as_deref()
+            .and_then(year)
+            .map_or(SortValue::Missing, SortValue::Number),
+        _ => sort_value(config, ctx, id, cited),
+    }
+}
+
+/// The heading value of a reference for a grouping key. Years are formatted as dates, with
+/// the "BC" or "AD" term of the locale for early years.
+fn group_value(key: &GroupSortKeys, ctx: &RenderContext) -> Option<String> {
+    match key {
+        GroupSortKeys::AsCited => None,
+        GroupSortKeys::Author => Some(
+            render_contributor(&ContributorRoles::Author, None, ctx).unwrap_or_else(|| {
+                ctx.locale
+                    .term(&LocalizedTermName::Anonymous, None, false)
+                    .to_string()
+            }),
+        ),
+        GroupSortKeys::Title => render_title(&Titles::Title, None, ctx),
+        GroupSortKeys::Year => Some(
+            ctx.reference
+                .issued
+                .as_deref()
+                .and_then(year)
+                .map(|year| {
+                    let date = Edtf::Date(Date {
+                        year,
+                        ..Date::default()
+                    });
+                    format_date(&date, None, Some(&Format::Year), &ctx.locale)
+                })
+                .unwrap_or_else(|| {
+                    ctx.locale
+                        .term(&LocalizedTermName::NoDate, None, false)
+                        .to_string()
+                }),
+        ),
+    }
+}
@@ -0,0 +1,134 @@
+//! Quotation marks, and punctuation next to closing quotation marks and punctuation marks.
+
+use crate::locale::Locale;
+use crate::output::OutputFormat;
+use crate::style::LocalizedTermName;
+
+/// Replace the straight double quotes of a text with the quotation marks of the locale: they
+/// open at the start of the text or after a space or an opening bracket, and close elsewhere.
+pub fn curl_quotes(value: &str, locale: &Locale) -> String {
+    if !value.contains('"') {
+        return value.to_string();
+    }
+    let open = locale.term(&LocalizedTermName::OpenQuote, None, false);
+    let close = locale.term(&LocalizedTermName::CloseQuote, None, false);
+    let mut result = String::with_capacity(value.len());
+    for c in value.chars() {
+        if c == '"' {
+            let opening = result
+                .chars()
+                .last()
+                .is_none_or(|p| p.is_whitespace() || matches!(p, '(' | '[' | '{'));
+            result.push_str(if opening { open } else { close });
+        } else {
+            result.push(c);
+        }
+    }
+    result
+}
+
+/// Write the hyphens of numeric ranges as en dashes: "12-14" becomes "12–14".
+pub fn range_dashes(value: &str) -> String {
+    let chars: Vec<char> = value.chars().collect();
+    chars
+        .iter()
+        .enumerate()
+        .map(|(index, c)| {
+            let between_digits = index > 0
+                && chars[index - 1].is_ascii_digit()
+                && chars.get(index + 1).is_some_and(char::is_ascii_digit);
+            if *c == '-' && between_digits {
+                '–'
+            } else {
+                *c
+            }
+        })
+        .collect()
+}
+
+/// Wrap a value in the quotation marks of the locale.
+///
+/// Quotations within the value are nested: its outer quotation marks become inner marks.
+pub fn quote(value: &str, locale: &Locale) -> String {
+    let mark = |name| locale.term(&name, None, false);
+    let (open, close) = (
+        mark(LocalizedTermName::OpenQuote),
+        mark(LocalizedTermName::CloseQuote),
+    );
+    let (open_inner, close_inner) = (
+        mark(LocalizedTermName::OpenInnerQuote),
+        mark(LocalizedTermName::CloseInnerQuote),
+    );
+
+    let mut result = String::with_capacity(value.len());
+    let mut depth = 0;
+    let mut rest = value;
+    while let Some(c) = rest.chars().next() {
+        if !open.is_empty() && rest.starts_with(open) {
+            result.push_str(open_inner);
+            rest = &rest[open.len()..];
+            depth += 1;
+        } else if depth > 0 && !close.is_empty() && rest.starts_with(close) {
+            result.push_str(close_inner);
+            rest = &rest[close.len()..];
+            depth -= 1;
+        } else {
+            result.push(c);
+            rest = &rest[c.len_utf8()..];
+        }
+    }
+    format!("{}{}{}", open, result, close)
+}
+
+/// Append a delimiter or closing punctuation to a rendered value, cleaning up the punctuation
+/// where they meet.
+///
+/// A period or comma starting the delimiter moves inside the closing quotation mark of the
+/// locale that ends the value when the locale puts punctuation in quotes, as in en-US
+/// ("“Title,”"), and stays outside otherwise. It is dropped when the value already ends with
+/// a period, question mark or exclamation mark, or with the same mark, whether or not
+/// quotation marks or closing markup of the output format stand between them: "Title?" and
+/// ". " make "Title? ". Only the end of the value is considered, so that the text of
+/// variables, such as an ellipsis or a URL, is kept as given.
+pub fn punctuate(
+    value: &str,
+    delimiter: &str,
+    locale: &Locale,
+    output: &dyn OutputFormat,
+) -> String {
+    let mut result = value.to_string();
+    let mark = match delimiter.chars().next() {
+        Some(mark) if mark.is_ascii_punctuation() => mark,
+        _ => {
+            result.push_str(delimiter);
+            return result;
+        }
+    };
+    let rest = &delimiter[mark.len_utf8()..];
+    let close = locale.term(&LocalizedTermName::CloseQuote, None, false);
+    // The end of the text before closing markup and quotation marks, and the position of the
+    // outermost closing quotation mark.
+    let mut end = result.len();
+    let mut quote_at = None;
+    loop {
+        end = output.markup_end(&result[..end]);
+        match result[..end].strip_suffix(close) {
+            Some(quoted) if !close.is_empty() => {
+                end = quoted.len();
+                quote_at.get_or_insert(end);
+            }
+            _ => break,
+        }
+    }
+    let last = result[..end].chars().last();
+    let duplicate = last == Some(mark) || (mark == '.' && matches!(last, Some('?' | '!')));
+    match quote_at {
+        _ if duplicate => {}
+        Some(at) if matches!(mark, '.' | ',') && locale.punctuation_in_quote() => {
+            result.insert(at, mark)
+        }
+        _ => result.push(mark),
+    }
+    result.push_str(rest);
+    result
+}
@@ -0,0 +1,142 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+use crate::bibliography::date::{Date, Edtf, Season};
+use crate::style::{GroupSortKeys, Order, SortConfig, Titles};
+
+use super::contributor::sort_name;
+use super::substitute::{effective_author, AuthorValue};
+use super::{render_title, Processor, RenderContext};
+
+/// Leading articles ignored when sorting by title.
+const ARTICLES: &[&str] = &["a", "an", "the"];
+
+impl<'a> Processor<'a> {
+    /// The keys of the references in bibliography order.
+    ///
+    /// References are sorted on each key of the bibliography `sort` option in turn; the sort
+    /// is stable, and references are otherwise ordered by key. References without a value for
+    /// a sort key come after those with one. The `year` key compares the year, month or season,
+    /// and day of issue, and the `title` key ignores a leading article.
+    pub fn sorted_ids(&self) -> Vec<&'a String> {
+        let options = self.bibliography_options();
+        let mut ids: Vec<&String> = self.bibliography.keys().collect();
+        ids.sort();
+        let sort = options.iter().rev().find_map(|o| o.sort.as_ref());
+        let sort = match sort {
+            Some(sort) if !sort.is_empty() => sort,
+            _ => return ids,
+        };
+        let cited = self.cited_order();
+        let mut keyed: Vec<(&String, Vec<SortValue>)> = ids
+            .into_iter()
+            .map(|id| {
+                let ctx = self.context(&self.bibliography[id], options.clone());
+                let values = sort
+                    .iter()
+                    .map(|config| sort_value(config, &ctx, id, &cited));
+                (id, values.collect())
+            })
+            .collect();
+        keyed.sort_by(|(_, a), (_, b)| {
+            sort.iter()
+                .zip(a.iter().zip(b.iter()))
+                .map(|(config, (a, b))| compare(config, a, b))
+                .find(|ordering| *ordering != Ordering::Equal)
+                .unwrap_or(Ordering::Equal)
+        });
+        keyed.into_iter().map(|(id, _)| id).collect()
+    }
+
+    /// The position of each cited reference in order of first citation.
+    pub(super) fn cited_order(&self) -> HashMap<&'a str, usize> {
+        let mut order = HashMap::new();
+        let cites = self
+            .citations
+            .iter()
+            .flat_map(|c| c.references.iter().flatten());
+        for id in cites.filter_map(|cite| cite.ref_id.as_deref()) {
+            let position = order.len();
+            order.entry(id).or_insert(position);
+        }
+        order
+    }
+}
+
+/// The value of a reference for a sort key.
+#[derive(PartialEq, PartialOrd)]
+pub(super) enum SortValue {
+    Number(i64),
+    /// The year, month or season, and day of a date; a missing month or day is 0.
+    Date(i64, u8, u8),
+    Text(Vec<String>),
+    Missing,
+}
+
+pub(super) fn sort_value(
+    config: &SortConfig,
+    ctx: &RenderContext,
+    id: &str,
+    cited: &HashMap<&str, usize>,
+) -> SortValue {
+    let value = match &config.key {
+        Some(GroupSortKeys::AsCited) => cited.get(id).map(|p| SortValue::Number(*p as i64)),
+        Some(GroupSortKeys::Author) => effective_author(ctx).map(|author| match author {
+            AuthorValue::Names(_, names) => {
+                SortValue::Text(names.iter().map(|n| sort_name(n).to_lowercase()).collect())
+            }
+            AuthorValue::Title(title) => SortValue::Text(vec![title_key(&title)]),
+        }),
+        Some(GroupSortKeys::Title) => render_title(&Titles::Title, None, ctx)
+            .map(|title| SortValue::Text(vec![title_key(&title)])),
+        Some(GroupSortKeys::Year) => ctx
+            .reference
+            .issued
+            .as_deref()
+            .and_then(|issued| issued.parse::<Edtf>().ok())
+            .and_then(|issued| issued.first().map(date_value)),
+        None => None,
+    };
+    value.unwrap_or(SortValue::Missing)
+}
+
+pub(super) fn compare(config: &SortConfig, a: &SortValue, b: &SortValue) -> Ordering {
+    match (a, b) {
+        (SortValue::Missing, SortValue::Missing) => Ordering::Equal,
+        (SortValue::Missing, _) => Ordering::Greater,
+        (_, SortValue::Missing) => Ordering::Less,
+        _ => {
+            let ordering = a.partial_cmp(b).unwrap_or(Ordering::Equal);
+            match config.order {
+                Some(Order::Descending) => ordering.reverse(),
+                Some(Order::Ascending) | None => ordering,
+            }
+        }
+    }
+}
+
+/// A title for sorting: lowercased, without a leading article.
+fn title_key(title: &str) -> String {
+    let title = title.trim().to_lowercase();
+    match title.split_once(' ') {
+        Some((first, rest)) if ARTICLES.contains(&first) => rest.trim_start().to_string(),
+        _ => title,
+    }
+}
+
+/// The sort value of a date; seasons sort with their first month.
+fn date_value(date: &Date) -> SortValue {
+    let month = match date.season {
+        Some(Season::Spring) => 3,
+        Some(Season::Summer) => 6,
+        Some(Season::Autumn) => 9,
+        Some(Season::Winter) => 12,
+        None => date.month.unwrap_or(0),
+    };
+    SortValue::Date(date.year, month, date.day.unwrap_or(0))
+}
+
+/// The year of an EDTF date, or of the first date of an interval.
+pub(super) fn year(date: &str) -> Option<i64> {
+    date.parse::<Edtf>().ok()?.year()
+}
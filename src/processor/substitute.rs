@@ -0,0 +1,54 @@
+use crate::bibliography::reference::{Contributor, InputReference};
+use crate::style::{ContributorRoles, Substitute, Substitution, Titles};
+
+use super::{render_title, RenderContext};
+
+/// The value in the author position of a reference.
+pub enum AuthorValue<'r> {
+    /// Contributor names, with the role they were taken from.
+    Names(ContributorRoles, &'r [Contributor]),
+    /// The title, substituted for a missing author.
+    Title(String),
+}
+
+/// The first listed variable to substitute for the author, when the reference has none.
+pub fn author_substitute(
+    reference: &InputReference,
+    substitution: Option<&Substitution>,
+) -> Option<Substitute> {
+    if names(&reference.author).is_some() {
+        return None;
+    }
+    let substitutes = substitution?.author.as_ref()?;
+    substitutes
+        .iter()
+        .find(|substitute| match substitute {
+            Substitute::Editor => names(&reference.editor).is_some(),
+            Substitute::Title => reference.title.is_some(),
+            Substitute::Translator => names(&reference.translator).is_some(),
+        })
+        .cloned()
+}
+
+/// The author of the reference being rendered, after substitution.
+pub fn effective_author<'r>(ctx: &RenderContext<'r>) -> Option<AuthorValue<'r>> {
+    let reference = ctx.reference;
+    match &ctx.substitute {
+        None => names(&reference.author).map(|n| AuthorValue::Names(ContributorRoles::Author, n)),
+        Some(Substitute::Editor) => {
+            names(&reference.editor).map(|n| AuthorValue::Names(ContributorRoles::Editor, n))
+        }
+        Some(Substitute::Translator) => names(&reference.translator)
+            .map(|n| AuthorValue::Names(ContributorRoles::Translator, n)),
+        Some(Substitute::Title) => render_title(&Titles::Title, None, ctx).map(AuthorValue::Title),
+    }
+}
+
+/// Whether a variable has been substituted for the author, so that it is not rendered again.
+pub fn is_substituted(ctx: &RenderContext, substitute: &Substitute) -> bool {
+    ctx.substitute.as_ref() == Some(substitute)
+}
+
+fn names(contributors: &Option<Vec<Contributor>>) -> Option<&[Contributor]> {
+    contributors.as_deref().filter(|c| !c.is_empty())
+}
@@ -0,0 +1,193 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::style::{Condition, Style, Template, TemplateComponent};
+
+/// Errors raised while resolving the named templates of a style.
+#[derive(Debug, PartialEq)]
+pub enum TemplateError {
+    /// A named template could not be parsed as a list of template components.
+    Invalid { key: String, message: String },
+    /// A template key does not name a template in the style.
+    Missing(String),
+    /// A template refers back to itself; the keys in the order they were followed.
+    Cycle(Vec<String>),
+}
+
+impl fmt::Display for TemplateError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TemplateError::Invalid { key, message } => {
+                write!(
+                    f,
+                    "template \"{}\" is not a valid template: {}",
+                    key, message
+                )
+            }
+            TemplateError::Missing(key) => {
+                write!(
+                    f,
+                    "template \"{}\" is not defined in the style templates",
+                    key
+                )
+            }
+            TemplateError::Cycle(keys) => {
+                write!(
+                    f,
+                    "templates refer to each other in a cycle: {}",
+                    keys.join(" -> ")
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for TemplateError {}
+
+/// Parse the named templates of a style and resolve every `templateKey` they contain.
+pub fn resolve_templates(
+    style: &Style,
+) -> Result<HashMap<String, Vec<TemplateComponent>>, TemplateError> {
+    let mut resolver = Resolver::new(style)?;
+    let mut keys: Vec<String> = resolver.parsed.keys().cloned().collect();
+    keys.sort();
+    for key in keys {
+        resolver.resolve_key(&key)?;
+    }
+    Ok(resolver.resolved)
+}
+
+/// A copy of the style where every `templateKey` is replaced by the template it names.
+///
+/// The named templates of the returned style are also stored in resolved form.
+pub fn flatten_style(style: &Style) -> Result<Style, TemplateError> {
+    let mut resolver = Resolver::new(style)?;
+    let mut flat = style.clone();
+    if let Some(bibliography) = flat.bibliography.as_mut() {
+        resolver.resolve_template(&mut bibliography.template)?;
+    }
+    if let Some(citation) = flat.citation.as_mut() {
+        resolver.resolve_template(&mut citation.template)?;
+        if let Some(integral) = citation.integral.as_mut() {
+            resolver.resolve_template(&mut integral.template)?;
+        }
+        if let Some(non_integral) = citation.non_integral.as_mut() {
+            resolver.resolve_template(&mut non_integral.template)?;
+        }
+    }
+    if flat.templates.is_some() {
+        let mut templates = HashMap::new();
+        let mut keys: Vec<String> = resolver.parsed.keys().cloned().collect();
+        keys.sort();
+        for key in keys {
+            let template = resolver.resolve_key(&key)?;
+            let value = serde_json::to_value(template).map_err(|err| TemplateError::Invalid {
+                key: key.clone(),
+                message: err.to_string(),
+            })?;
+            templates.insert(key, Some(value));
+        }
+        flat.templates = Some(templates);
+    }
+    Ok(flat)
+}
+
+struct Resolver {
+    parsed: HashMap<String, Vec<TemplateComponent>>,
+    resolved: HashMap<String, Vec<TemplateComponent>>,
+    /// The keys currently being resolved, outermost first.
+    stack: Vec<String>,
+}
+
+impl Resolver {
+    fn new(style: &Style) -> Result<Self, TemplateError> {
+        let mut parsed = HashMap::new();
+        for (key, value) in style.templates.iter().flatten() {
+            let template = match value {
+                Some(value) => {
+                    serde_json::from_value(value.clone()).map_err(|err| TemplateError::Invalid {
+                        key: key.clone(),
+                        message: err.to_string(),
+                    })?
+                }
+                None => Vec::new(),
+            };
+            parsed.insert(key.clone(), template);
+        }
+        Ok(Resolver {
+            parsed,
+            resolved: HashMap::new(),
+            stack: Vec::new(),
+        })
+    }
+
+    fn resolve_key(&mut self, key: &str) -> Result<Vec<TemplateComponent>, TemplateError> {
+        if let Some(template) = self.resolved.get(key) {
+            return Ok(template.clone());
+        }
+        if let Some(start) = self.stack.iter().position(|k| k == key) {
+            let mut keys = self.stack[start..].to_vec();
+            keys.push(key.to_string());
+            return Err(TemplateError::Cycle(keys));
+        }
+        let mut template = match self.parsed.get(key) {
+            Some(template) => template.clone(),
+            None => return Err(TemplateError::Missing(key.to_string())),
+        };
+        self.stack.push(key.to_string());
+        self.resolve_components(&mut template)?;
+        self.stack.pop();
+        self.resolved.insert(key.to_string(), template.clone());
+        Ok(template)
+    }
+
+    fn resolve_template(
+        &mut self,
+        template: &mut Option<Vec<TemplateComponent>>,
+    ) -> Result<(), TemplateError> {
+        match template {
+            Some(components) => self.resolve_components(components),
+            None => Ok(()),
+        }
+    }
+
+    fn resolve_components(
+        &mut self,
+        components: &mut [TemplateComponent],
+    ) -> Result<(), TemplateError> {
+        for component in components.iter_mut() {
+            self.resolve_reference(&mut component.template_key, &mut component.template)?;
+            for condition in component.when.iter_mut().flatten() {
+                let Condition {
+                    template_key,
+                    template,
+                    ..
+                } = condition;
+                self.resolve_reference(template_key, template)?;
+            }
+            if let Some(Template {
+                template_key,
+                template,
+            }) = component.template_component_else.as_mut()
+            {
+                self.resolve_reference(template_key, template)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Replace a template key with the template it names, or resolve an inline template.
+    fn resolve_reference(
+        &mut self,
+        template_key: &mut Option<String>,
+        template: &mut Option<Vec<TemplateComponent>>,
+    ) -> Result<(), TemplateError> {
+        match template_key.take() {
+            Some(key) => {
+                *template = Some(self.resolve_key(&key)?);
+                Ok(())
+            }
+            None => self.resolve_template(template),
+        }
+    }
+}
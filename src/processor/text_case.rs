@@ -0,0 +1,82 @@
+use crate::style::TextCase;
+
+/// Words not capitalized in title case, unless first, last or after a colon.
+const STOP_WORDS: &[&str] = &[
+    "a", "an", "and", "as", "at", "but", "by", "down", "for", "from", "in", "into", "nor", "of",
+    "on", "onto", "or", "over", "so", "the", "till", "to", "up", "v", "via", "vs", "with", "yet",
+];
+
+/// Transform the case of text. Title case applies to English text only.
+///
+/// Words with capitals after their first letter, such as "DNA" or "iPhone", keep their case
+/// in sentence and title case. Words after a colon start a new sentence.
+pub(super) fn text_case(value: &str, case: &TextCase, english: bool) -> String {
+    match case {
+        TextCase::CapitalizeFirst => capitalize(value),
+        TextCase::Lowercase => value.to_lowercase(),
+        TextCase::Uppercase => value.to_uppercase(),
+        TextCase::Sentence => transform_words(value, |word, first, _| {
+            if first {
+                capitalize(word)
+            } else if is_capitalized(word) {
+                word.to_lowercase()
+            } else {
+                word.to_string()
+            }
+        }),
+        TextCase::Title if english => transform_words(value, |word, first, last| {
+            let bare = word
+                .trim_matches(|c: char| !c.is_alphabetic())
+                .to_lowercase();
+            let stop_word = STOP_WORDS.contains(&bare.as_str());
+            if stop_word && !first && !last && (is_lowercase(word) || is_capitalized(word)) {
+                word.to_lowercase()
+            } else if is_lowercase(word) {
+                word.split('-')
+                    .map(capitalize)
+                    .collect::<Vec<_>>()
+                    .join("-")
+            } else {
+                word.to_string()
+            }
+        }),
+        TextCase::Title => value.to_string(),
+    }
+}
+
+/// Transform each word of a text, given whether it starts a sentence and whether it is the
+/// last word.
+fn transform_words(value: &str, transform: impl Fn(&str, bool, bool) -> String) -> String {
+    let words: Vec<&str> = value.split(' ').collect();
+    let mut first = true;
+    let mut result = Vec::with_capacity(words.len());
+    for (index, word) in words.iter().enumerate() {
+        result.push(transform(word, first, index == words.len() - 1));
+        if !word.is_empty() {
+            first = word.ends_with([':', '?', '!']);
+        }
+    }
+    result.join(" ")
+}
+
+/// Upper-case the first letter of a text.
+fn capitalize(value: &str) -> String {
+    match value.char_indices().find(|(_, c)| c.is_alphabetic()) {
+        Some((index, c)) => {
+            let rest = &value[index + c.len_utf8()..];
+            format!("{}{}{}", &value[..index], c.to_uppercase(), rest)
+        }
+        None => value.to_string(),
+    }
+}
+
+/// Whether a word has no capitals.
+fn is_lowercase(word: &str) -> bool {
+    !word.chars().any(char::is_uppercase)
+}
+
+/// Whether a word has a capital first letter and no other.
+fn is_capitalized(word: &str) -> bool {
+    let mut letters = word.chars().filter(|c| c.is_alphabetic());
+    letters.next().is_some_and(char::is_uppercase) && letters.all(char::is_lowercase)
+}
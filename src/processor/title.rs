@@ -0,0 +1,56 @@
+use crate::bibliography::reference::TitleUnion;
+use crate::style::{Format, Titles};
+
+use super::RenderContext;
+
+/// Render a title in a format: the full title by default, the main title, the subtitles, or
+/// the short title.
+///
+/// The parts of a structured title are joined with the subtitle delimiter of the locale.
+/// Plain string titles are rendered as given in full, and split at the first colon into a
+/// main title and a subtitle for the other formats. The short title is the main title, unless
+/// a structured title gives one.
+pub(super) fn render_title(
+    title: &Titles,
+    format: Option<&Format>,
+    ctx: &RenderContext,
+) -> Option<String> {
+    let reference = ctx.reference;
+    let title = match title {
+        Titles::Title => reference.title.as_ref()?,
+        Titles::ContainerTitle => reference.container_title.as_ref()?,
+    };
+    let delimiter = ctx.locale.subtitle_delimiter();
+    let value = match (title, format) {
+        (TitleUnion::String(title), Some(Format::Main | Format::Short)) => {
+            split_title(title).0.to_string()
+        }
+        (TitleUnion::String(title), Some(Format::Sub)) => split_title(title).1?.to_string(),
+        (TitleUnion::String(title), _) => title.clone(),
+        (TitleUnion::TitleStructured(title), Some(Format::Main)) => title.main.clone(),
+        (TitleUnion::TitleStructured(title), Some(Format::Short)) => {
+            title.short.as_ref().unwrap_or(&title.main).clone()
+        }
+        (TitleUnion::TitleStructured(title), Some(Format::Sub)) => match title.sub.as_slice() {
+            [] => return None,
+            sub => sub.join(delimiter),
+        },
+        (TitleUnion::TitleStructured(title), _) => match &title.full {
+            Some(full) => full.clone(),
+            None => std::iter::once(&title.main)
+                .chain(&title.sub)
+                .map(String::as_str)
+                .collect::<Vec<_>>()
+                .join(delimiter),
+        },
+    };
+    Some(value)
+}
+
+/// Split a title at the first colon into the main title and the subtitle, if any.
+fn split_title(title: &str) -> (&str, Option<&str>) {
+    match title.split_once([':', '：']) {
+        Some((main, sub)) if !sub.trim().is_empty() => (main.trim_end(), Some(sub.trim_start())),
+        _ => (title, None),
+    }
+}
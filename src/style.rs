@@ -15,7 +15,7 @@ use serde::{Serialize, Deserialize};
 use std::collections::HashMap;
 
 /// A CSL Style.
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Style {
     /// The bibliography specification.
     pub bibliography: Option<BibliographyStyle>,
@@ -34,7 +34,7 @@ pub struct Style {
 }
 
 /// The bibliography specification.
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct BibliographyStyle {
     pub heading: Option<String>,
@@ -44,7 +44,7 @@ pub struct BibliographyStyle {
 }
 
 /// Parameter groups.
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct OptionGroup {
     /// Contributor list formatting configuration.
     pub contributors: Option<ContributorListFormatting>,
@@ -53,7 +53,7 @@ pub struct OptionGroup {
     /// Disambiguation configuration of rendererd group display names.
     pub disambiguate: Option<Disambiguation>,
     /// Grouping configuration.
-    pub group: Option<Vec<GroupSortKeys>>,
+    pub group: Option<Vec<Group>>,
     /// Localization configuration.
     pub localization: Option<Localization>,
     /// Sorting configuration.
@@ -63,7 +63,7 @@ pub struct OptionGroup {
 }
 
 /// Contributor list formatting configuration.
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ContributorListFormatting {
     /// The delimiter between last and second-to-last item.
@@ -80,6 +80,19 @@ pub struct ContributorListFormatting {
     /// Format a contributor name as sorted.
     pub display_as_sort: Option<ContributorScope>,
     pub emph: Option<bool>,
+    /// Initialize given names, with this string after each initial.
+    ///
+    /// The ". " value produces:
+    ///
+    /// >  Doe, J. A.
+    ///
+    /// The "." value produces:
+    ///
+    /// >  Doe, J.A.
+    pub initialize_with: Option<String>,
+    /// Keep the hyphen in the initials of hyphenated given names: "J.-P." for "Jean-Paul".
+    /// Defaults to true.
+    pub initialize_with_hyphen: Option<bool>,
     /// Configuring of the display of contributor rolee annotations.
     pub role: Option<RoleOption>,
     /// Configuration for contributor list shortening.
@@ -98,7 +111,7 @@ pub struct ContributorListFormatting {
 /// The "symbol" value produces:
 ///
 /// >  Doe, Johnson & Smith
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum AndAsString {
     Symbol,
@@ -108,7 +121,7 @@ pub enum AndAsString {
 /// Format a contributor name as sorted.
 ///
 /// Which of the contributor names in a list to apply the transformation.
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum ContributorScope {
     All,
@@ -119,7 +132,7 @@ pub enum ContributorScope {
 /// Configuring of the display of contributor rolee annotations.
 ///
 /// Contributor role configuration.
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct RoleOption {
     pub bold: Option<bool>,
     pub emph: Option<bool>,
@@ -172,7 +185,7 @@ pub struct RoleOption {
 /// ### `verb-short`
 ///
 /// > ed. Jane Smith
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 pub enum Form {
     Long,
@@ -182,17 +195,27 @@ pub enum Form {
     VerbShort,
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum ContributorRoles {
     Author,
+    #[serde(rename = "collection-editor")]
+    CollectionEditor,
+    Composer,
+    #[serde(rename = "container-author")]
+    ContainerAuthor,
+    Director,
     Editor,
+    Illustrator,
+    Interviewer,
     Publisher,
+    Recipient,
+    Translator,
 }
 
 /// The symbol pair to wrap around one or more rendering components.
 /// Interaction with surrounding punctuation is localized.
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum WrapPunctuation {
     Brackets,
@@ -201,7 +224,7 @@ pub enum WrapPunctuation {
 }
 
 /// Configuration for contributor list shortening.
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ContributorListShortening {
     /// When shortening, replace omitted names with this term form.
@@ -296,7 +319,7 @@ pub struct ContributorListShortening {
 /// ### `long`
 ///
 /// > Doe, Johnson, and others
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum AtAl {
     Long,
@@ -369,16 +392,18 @@ pub enum AtAl {
 ///
 /// - 2 names: “J. Doe and T. Williams”
 /// - 3 names: “J. Doe, S. Smith and T. Williams”
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum DelimiterPrecedes {
+    #[serde(rename = "after-inverted-name")]
+    AfterInvertedName,
     Always,
     Contextual,
     Never,
 }
 
 /// Date formatting configuration.
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct DateFormatting {
     pub date: Option<EStyle>,
     pub month: Option<MonthStyle>,
@@ -386,7 +411,7 @@ pub struct DateFormatting {
     pub year: Option<YearStyle>,
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum EStyle {
     Full,
@@ -395,7 +420,7 @@ pub enum EStyle {
     Short,
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum MonthStyle {
     Long,
@@ -406,7 +431,7 @@ pub enum MonthStyle {
     The2Digit,
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum YearStyle {
     Numeric,
@@ -417,14 +442,14 @@ pub enum YearStyle {
 /// Disambiguation configuration of rendererd group display names.
 ///
 /// Disambiguation of rendered group display name configuration.
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Disambiguation {
     pub add_names: Option<AddNames>,
     pub add_year_suffix: Option<bool>,
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 pub enum AddNames {
     All,
@@ -437,7 +462,35 @@ pub enum AddNames {
     PrimaryWithInitials,
 }
 
-#[derive(Serialize, Deserialize)]
+/// A bibliography grouping key, given alone or with the delimiter after its value in group
+/// headings.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Group {
+    Key(GroupSortKeys),
+    Delimited {
+        /// The string between the value of the key and that of the next key.
+        delimiter: Option<String>,
+        key: GroupSortKeys,
+    },
+}
+
+impl Group {
+    pub fn key(&self) -> &GroupSortKeys {
+        match self {
+            Group::Key(key) | Group::Delimited { key, .. } => key,
+        }
+    }
+
+    pub fn delimiter(&self) -> Option<&str> {
+        match self {
+            Group::Key(_) => None,
+            Group::Delimited { delimiter, .. } => delimiter.as_deref(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 pub enum GroupSortKeys {
     #[serde(rename = "as-cited")]
@@ -450,7 +503,7 @@ pub enum GroupSortKeys {
 /// Localization configuration.
 ///
 /// Terms and data localization configuration.
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Localization {
     /// The scope to use for localization.
     ///
@@ -463,7 +516,7 @@ pub struct Localization {
 ///
 /// "per-item" uses the locale of the reference item, and "global" uses the target language
 /// across all references.
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 pub enum Scope {
     Global,
@@ -472,13 +525,13 @@ pub enum Scope {
 }
 
 /// Reference sorting configuration.
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct SortConfig {
     pub key: Option<GroupSortKeys>,
     pub order: Option<Order>,
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum Order {
     Ascending,
@@ -488,7 +541,7 @@ pub enum Order {
 /// Substitution configuration.
 ///
 /// Substitution of variable configuration.
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Substitution {
     /// When author is nil, substitute the first non-nil listed variable.
     /// Once a substitution is made, the substituted variable shall be set to nil for purposes of
@@ -496,7 +549,7 @@ pub struct Substitution {
     pub author: Option<Vec<Substitute>>,
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum Substitute {
     Editor,
@@ -507,7 +560,7 @@ pub enum Substitute {
 /// A template defined inline.
 ///
 /// A template is called by name.
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Condition {
     /// The conditions that must be true for the templates to render.
@@ -536,7 +589,7 @@ pub struct Condition {
 /// A template defined inline.
 ///
 /// A template is called by name.
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Template {
     pub template: Option<Vec<TemplateComponent>>,
@@ -564,7 +617,7 @@ pub struct Template {
 /// Non-localized plain text.
 ///
 /// Localized strings.
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct TemplateComponent {
     pub options: Option<OptionGroup>,
@@ -582,6 +635,8 @@ pub struct TemplateComponent {
     pub format: Option<Format>,
     pub title: Option<Titles>,
     pub text: Option<String>,
+    /// The case to render text in.
+    pub text_case: Option<TextCase>,
     pub term: Option<LocalizedTermName>,
     /// When all of the when conditions are nil, format the children.
     #[serde(rename = "else")]
@@ -591,7 +646,7 @@ pub struct TemplateComponent {
 }
 
 /// The conditions that must be true for the templates to render.
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum Match {
     All,
@@ -599,27 +654,47 @@ pub enum Match {
     None,
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 pub enum Variables {
+    Abstract,
     Accessed,
+    Archive,
     Article,
     Author,
     Book,
     Chapter,
+    CollectionEditor,
+    Composer,
+    ContainerAuthor,
     #[serde(rename = "container-title")]
     ContainerTitle,
+    Director,
+    Doi,
+    Edition,
     Editor,
+    Genre,
+    Illustrator,
+    Interviewer,
+    Isbn,
+    Issn,
     Issue,
     Issued,
+    Language,
+    Medium,
+    Note,
     Pages,
     Publisher,
+    PublisherPlace,
+    Recipient,
     Title,
+    Translator,
+    Url,
     Volume,
 }
 
 /// Does the date conform to EDTF?
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum Dates {
     Accessed,
@@ -627,22 +702,177 @@ pub enum Dates {
 }
 
 /// Is the item variable a number?
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum Locators {
     Chapter,
     Page,
 }
 
-#[derive(Serialize, Deserialize)]
+/// The type of a reference. Types are named as the terms for them are, and also accept the
+/// name of the CSL 1.0 type they correspond to where that is unambiguous. Unknown types are
+/// read as `other`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum ReferenceTypes {
+    Album,
+    /// An article in a scholarly journal.
+    #[serde(alias = "article-journal")]
     Article,
+    #[serde(rename = "article-magazine")]
+    ArticleMagazine,
+    #[serde(rename = "article-newspaper")]
+    ArticleNewspaper,
+    #[serde(rename = "audio-recording", alias = "song")]
+    AudioRecording,
+    Bill,
     Book,
     Chapter,
+    #[serde(rename = "conference-paper", alias = "paper-conference")]
+    ConferencePaper,
+    Dataset,
+    /// An entry in a dictionary or encyclopedia.
+    #[serde(alias = "entry-dictionary", alias = "entry-encyclopedia")]
+    Entry,
+    #[serde(alias = "motion_picture")]
+    Film,
+    Interview,
+    #[serde(rename = "legal-case", alias = "legal_case")]
+    LegalCase,
+    Letter,
+    Manuscript,
+    Map,
+    Patent,
+    #[serde(rename = "personal-communication", alias = "personal_communication")]
+    PersonalCommunication,
+    Podcast,
+    #[serde(rename = "podcast-episode")]
+    PodcastEpisode,
+    /// A post on a blog, forum or social network.
+    #[serde(alias = "post-weblog")]
+    Post,
+    Preprint,
+    #[serde(rename = "radio-broadcast")]
+    RadioBroadcast,
+    #[serde(rename = "radio-series")]
+    RadioSeries,
+    #[serde(rename = "radio-series-episode")]
+    RadioSeriesEpisode,
+    Report,
+    Software,
+    Speech,
+    Standard,
+    #[serde(alias = "legislation")]
+    Statute,
+    #[serde(rename = "television-broadcast")]
+    TelevisionBroadcast,
+    #[serde(rename = "television-series")]
+    TelevisionSeries,
+    #[serde(rename = "television-series-episode")]
+    TelevisionSeriesEpisode,
+    Thesis,
+    Treaty,
+    Video,
+    Webpage,
+    #[serde(rename = "working-paper")]
+    WorkingPaper,
+    /// Any other type, such as the CSL 1.0 "pamphlet" or "review".
+    #[serde(other)]
+    Other,
+}
+
+impl ReferenceTypes {
+    /// The CSL 1.0 type of the reference type, such as "article-journal" for an article.
+    /// Types without a CSL 1.0 counterpart have the nearest one: "broadcast" for radio and
+    /// television, "song" for audio recordings, "report" for working papers and "document" for
+    /// other types.
+    pub fn csl_type(&self) -> &'static str {
+        match self {
+            ReferenceTypes::Album => "song",
+            ReferenceTypes::Article => "article-journal",
+            ReferenceTypes::ArticleMagazine => "article-magazine",
+            ReferenceTypes::ArticleNewspaper => "article-newspaper",
+            ReferenceTypes::AudioRecording => "song",
+            ReferenceTypes::Bill => "bill",
+            ReferenceTypes::Book => "book",
+            ReferenceTypes::Chapter => "chapter",
+            ReferenceTypes::ConferencePaper => "paper-conference",
+            ReferenceTypes::Dataset => "dataset",
+            ReferenceTypes::Entry => "entry",
+            ReferenceTypes::Film => "motion_picture",
+            ReferenceTypes::Interview => "interview",
+            ReferenceTypes::LegalCase => "legal_case",
+            ReferenceTypes::Letter => "letter",
+            ReferenceTypes::Manuscript => "manuscript",
+            ReferenceTypes::Map => "map",
+            ReferenceTypes::Patent => "patent",
+            ReferenceTypes::PersonalCommunication => "personal_communication",
+            ReferenceTypes::Podcast => "broadcast",
+            ReferenceTypes::PodcastEpisode => "broadcast",
+            ReferenceTypes::Post => "post",
+            ReferenceTypes::Preprint => "article",
+            ReferenceTypes::RadioBroadcast => "broadcast",
+            ReferenceTypes::RadioSeries => "broadcast",
+            ReferenceTypes::RadioSeriesEpisode => "broadcast",
+            ReferenceTypes::Report => "report",
+            ReferenceTypes::Software => "software",
+            ReferenceTypes::Speech => "speech",
+            ReferenceTypes::Standard => "standard",
+            ReferenceTypes::Statute => "legislation",
+            ReferenceTypes::TelevisionBroadcast => "broadcast",
+            ReferenceTypes::TelevisionSeries => "broadcast",
+            ReferenceTypes::TelevisionSeriesEpisode => "broadcast",
+            ReferenceTypes::Thesis => "thesis",
+            ReferenceTypes::Treaty => "treaty",
+            ReferenceTypes::Video => "motion_picture",
+            ReferenceTypes::Webpage => "webpage",
+            ReferenceTypes::WorkingPaper => "report",
+            ReferenceTypes::Other => "document",
+        }
+    }
+
+    /// The reference type of a CSL 1.0 type. Types that several reference types share map to
+    /// the most general of them, as "broadcast" to a television broadcast; the CSL 1.0 types
+    /// with no counterpart, such as "classic" or "figure", have none.
+    pub fn from_csl_type(name: &str) -> Option<ReferenceTypes> {
+        let reference_type = match name {
+            "article" => ReferenceTypes::Preprint,
+            "article-journal" => ReferenceTypes::Article,
+            "article-magazine" => ReferenceTypes::ArticleMagazine,
+            "article-newspaper" => ReferenceTypes::ArticleNewspaper,
+            "bill" => ReferenceTypes::Bill,
+            "book" => ReferenceTypes::Book,
+            "broadcast" => ReferenceTypes::TelevisionBroadcast,
+            "chapter" => ReferenceTypes::Chapter,
+            "dataset" => ReferenceTypes::Dataset,
+            "document" => ReferenceTypes::Other,
+            "entry" | "entry-dictionary" | "entry-encyclopedia" => ReferenceTypes::Entry,
+            "interview" => ReferenceTypes::Interview,
+            "legal_case" => ReferenceTypes::LegalCase,
+            "legislation" => ReferenceTypes::Statute,
+            "letter" => ReferenceTypes::Letter,
+            "manuscript" => ReferenceTypes::Manuscript,
+            "map" => ReferenceTypes::Map,
+            "motion_picture" => ReferenceTypes::Film,
+            "paper-conference" => ReferenceTypes::ConferencePaper,
+            "patent" => ReferenceTypes::Patent,
+            "personal_communication" => ReferenceTypes::PersonalCommunication,
+            "post" | "post-weblog" => ReferenceTypes::Post,
+            "report" => ReferenceTypes::Report,
+            "software" => ReferenceTypes::Software,
+            "song" => ReferenceTypes::AudioRecording,
+            "speech" => ReferenceTypes::Speech,
+            "standard" => ReferenceTypes::Standard,
+            "thesis" => ReferenceTypes::Thesis,
+            "treaty" => ReferenceTypes::Treaty,
+            "webpage" => ReferenceTypes::Webpage,
+            _ => return None,
+        };
+        Some(reference_type)
+    }
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 pub enum Format {
     Full,
@@ -658,7 +888,7 @@ pub enum Format {
 }
 
 /// The unique human-readable identifier for a term.
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Hash)]
 #[serde(rename_all = "kebab-case")]
 pub enum LocalizedTermName {
     Accessed,
@@ -677,6 +907,7 @@ pub enum LocalizedTermName {
     At,
     #[serde(rename = "audio-recording")]
     AudioRecording,
+    Author,
     #[serde(rename = "available-at")]
     AvailableAt,
     Bc,
@@ -688,7 +919,18 @@ pub enum LocalizedTermName {
     Chapter,
     Circa,
     Cited,
+    #[serde(rename = "close-inner-quote")]
+    CloseInnerQuote,
+    #[serde(rename = "close-quote")]
+    CloseQuote,
+    #[serde(rename = "collection-editor")]
+    CollectionEditor,
     Column,
+    Composer,
+    #[serde(rename = "container-author")]
+    ContainerAuthor,
+    Director,
+    Editor,
     Elocation,
     Equation,
     #[serde(rename = "et-al")]
@@ -700,16 +942,42 @@ pub enum LocalizedTermName {
     From,
     Henceforth,
     Ibid,
+    Illustrator,
     In,
     #[serde(rename = "in-press")]
     InPress,
     Internet,
     Interview,
+    Interviewer,
     Issue,
     Letter,
     Line,
     #[serde(rename = "loc-cit")]
     LocCit,
+    #[serde(rename = "month-01")]
+    Month01,
+    #[serde(rename = "month-02")]
+    Month02,
+    #[serde(rename = "month-03")]
+    Month03,
+    #[serde(rename = "month-04")]
+    Month04,
+    #[serde(rename = "month-05")]
+    Month05,
+    #[serde(rename = "month-06")]
+    Month06,
+    #[serde(rename = "month-07")]
+    Month07,
+    #[serde(rename = "month-08")]
+    Month08,
+    #[serde(rename = "month-09")]
+    Month09,
+    #[serde(rename = "month-10")]
+    Month10,
+    #[serde(rename = "month-11")]
+    Month11,
+    #[serde(rename = "month-12")]
+    Month12,
     #[serde(rename = "no date")]
     NoDate,
     #[serde(rename = "no-place")]
@@ -721,6 +989,10 @@ pub enum LocalizedTermName {
     Online,
     #[serde(rename = "op-cit")]
     OpCit,
+    #[serde(rename = "open-inner-quote")]
+    OpenInnerQuote,
+    #[serde(rename = "open-quote")]
+    OpenQuote,
     Opus,
     #[serde(rename = "original-work-published")]
     OriginalWorkPublished,
@@ -735,12 +1007,14 @@ pub enum LocalizedTermName {
     Preprint,
     #[serde(rename = "presented-at")]
     PresentedAt,
+    Publisher,
     #[serde(rename = "radio-broadcast")]
     RadioBroadcast,
     #[serde(rename = "radio-series")]
     RadioSeries,
     #[serde(rename = "radio-series-episode")]
     RadioSeriesEpisode,
+    Recipient,
     Reference,
     Retrieved,
     #[serde(rename = "review-of")]
@@ -748,6 +1022,14 @@ pub enum LocalizedTermName {
     Rule,
     Scale,
     Scene,
+    #[serde(rename = "season-01")]
+    Season01,
+    #[serde(rename = "season-02")]
+    Season02,
+    #[serde(rename = "season-03")]
+    Season03,
+    #[serde(rename = "season-04")]
+    Season04,
     Section,
     #[serde(rename = "special-issue")]
     SpecialIssue,
@@ -766,6 +1048,7 @@ pub enum LocalizedTermName {
     Timestamp,
     #[serde(rename = "title-locator")]
     TitleLocator,
+    Translator,
     Verse,
     Version,
     Video,
@@ -774,7 +1057,24 @@ pub enum LocalizedTermName {
     WorkingPaper,
 }
 
-#[derive(Serialize, Deserialize)]
+/// A transform of the case of rendered text.
+///
+/// Title case applies to English text only, and leaves words with capitals after the first
+/// letter, such as acronyms, as given.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum TextCase {
+    /// "The origin of species" from "the origin of species".
+    CapitalizeFirst,
+    Lowercase,
+    /// "The origin of species" from "The Origin of Species".
+    Sentence,
+    /// "The Origin of Species" from "The origin of species".
+    Title,
+    Uppercase,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 pub enum Titles {
     #[serde(rename = "container-title")]
@@ -782,15 +1082,27 @@ pub enum Titles {
     Title,
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum SimpleTypes {
+    Abstract,
+    Archive,
+    Doi,
+    Edition,
+    Genre,
+    Isbn,
+    Issn,
     Issue,
+    Medium,
+    Note,
     Pages,
+    #[serde(rename = "publisher-place")]
+    PublisherPlace,
+    Url,
     Volume,
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum StyleCategory {
     Biology,
@@ -800,7 +1112,7 @@ pub enum StyleCategory {
 }
 
 /// The citation specification.
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CitationStyle {
     /// Integral citations are those where the author is printed inline in the text; aka "in
@@ -819,13 +1131,13 @@ pub struct CitationStyle {
 ///
 /// Non-integral citations are those where the author is incorporated in the citation, and
 /// not printed inline in the text.
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct RenderList {
     pub options: Option<OptionGroup>,
     pub template: Option<Vec<TemplateComponent>>,
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum Placement {
     Inline,
@@ -0,0 +1,116 @@
+use csln_rs::bibliography::InputBibliography;
+use csln_rs::citation::Citation;
+use csln_rs::processor::{Processor, ProcessorError};
+use csln_rs::style::Style;
+
+const BIBLIOGRAPHY: &str = r#"
+doe: {title: A Study, author: [{name: "Jane Doe"}], issued: "2020"}
+roe: {title: Another Study, author: [{name: "Al Roe"}], issued: "2019"}
+"#;
+
+const STYLE: &str = r#"
+citation:
+  template:
+    - contributor: author
+      format: short
+    - date: issued
+      format: year
+  integral:
+    template:
+      - contributor: author
+        format: short
+      - date: issued
+        format: year
+        wrap: parentheses
+"#;
+
+/// The rendered citation, or the error, per the style.
+fn cite(style: &str, citation: &str) -> Result<String, ProcessorError> {
+    let style: Style = serde_yaml::from_str(style).unwrap();
+    let bibliography: InputBibliography = serde_yaml::from_str(BIBLIOGRAPHY).unwrap();
+    let citation: Citation = serde_yaml::from_str(citation).unwrap();
+    Processor::new(&style, &bibliography).render_citation(&citation)
+}
+
+#[test]
+fn templates_per_citation_mode() {
+    assert_eq!(
+        cite(STYLE, "{mode: integral, references: [{refID: doe}]}").unwrap(),
+        "Doe (2020)"
+    );
+    assert_eq!(
+        cite(STYLE, "{references: [{refID: doe}, {refID: roe}]}").unwrap(),
+        "(Doe, 2020; Roe, 2019)"
+    );
+    let non_integral = format!(
+        "{}  nonIntegral:\n    template:\n      - title: title\n",
+        STYLE
+    );
+    assert_eq!(
+        cite(
+            &non_integral,
+            "{mode: nonIntegral, references: [{refID: doe}]}"
+        )
+        .unwrap(),
+        "(A Study)"
+    );
+    assert_eq!(
+        cite(
+            &non_integral,
+            "{mode: integral, references: [{refID: doe}]}"
+        )
+        .unwrap(),
+        "Doe (2020)"
+    );
+}
+
+#[test]
+fn prefixes_and_suffixes() {
+    assert_eq!(
+        cite(
+            STYLE,
+            "{prefix: see, suffix: for details, references: [{refID: doe, prefix: e.g.}, {refID: roe}]}"
+        )
+        .unwrap(),
+        "(see e.g. Doe, 2020; Roe, 2019 for details)"
+    );
+    assert_eq!(
+        cite(
+            STYLE,
+            "{references: [{refID: doe, suffix: [{page: \"12-14\"}]}, {refID: roe, suffix: [ch. 2]}]}"
+        )
+        .unwrap(),
+        "(Doe, 2020, pages 12–14; Roe, 2019, ch. 2)"
+    );
+    assert_eq!(
+        cite(
+            STYLE,
+            "{references: [{refID: doe, suffix: [{page: \"5\"}]}, {refID: roe, suffix: [{chapter: \"2, 4\"}]}]}"
+        )
+        .unwrap(),
+        "(Doe, 2020, page 5; Roe, 2019, chapters 2, 4)"
+    );
+}
+
+#[test]
+fn note_placement() {
+    let note = format!("{}  placement: note\n", STYLE);
+    assert_eq!(
+        cite(&note, "{references: [{refID: doe}]}").unwrap(),
+        "Doe, 2020"
+    );
+}
+
+#[test]
+fn unknown_and_missing_references() {
+    let error = cite(STYLE, "{references: [{refID: doe}, {refID: poe}]}").unwrap_err();
+    assert_eq!(error, ProcessorError::UnknownReference("poe".to_string()));
+    assert_eq!(
+        error.to_string(),
+        "reference \"poe\" is not in the bibliography"
+    );
+    assert_eq!(
+        cite(STYLE, "{references: [{prefix: see}]}").unwrap_err(),
+        ProcessorError::MissingReferenceId
+    );
+}
@@ -0,0 +1,74 @@
+use csln_rs::bibliography::InputReference;
+use csln_rs::processor::condition::matches;
+use csln_rs::processor::RenderContext;
+use csln_rs::style::Condition;
+
+const REFERENCE: &str = r#"
+type: book
+title: A Book
+author: [{name: "Jane Doe"}]
+issued: "2020"
+"#;
+
+/// Whether the condition holds for the reference.
+fn holds(condition: &str) -> bool {
+    let reference: InputReference = serde_yaml::from_str(REFERENCE).unwrap();
+    let condition: Condition = serde_yaml::from_str(condition).unwrap();
+    matches(&condition, &RenderContext::new(&reference, Vec::new()))
+}
+
+#[test]
+fn match_all() {
+    assert!(holds("{isRefType: [book], hasVariable: [title]}"));
+    assert!(holds(
+        "{match: all, isRefType: [book], hasVariable: [title]}"
+    ));
+    assert!(!holds(
+        "{match: all, isRefType: [book], hasVariable: [editor]}"
+    ));
+}
+
+#[test]
+fn match_any() {
+    assert!(holds(
+        "{match: any, isRefType: [chapter], hasVariable: [title]}"
+    ));
+    assert!(!holds(
+        "{match: any, isRefType: [chapter], hasVariable: [editor]}"
+    ));
+}
+
+#[test]
+fn match_none() {
+    assert!(holds(
+        "{match: none, isRefType: [chapter], hasVariable: [editor]}"
+    ));
+    assert!(!holds(
+        "{match: none, isRefType: [chapter], hasVariable: [title]}"
+    ));
+}
+
+#[test]
+fn several_tests() {
+    let condition = "{match: MATCH, isRefType: [book], isEDTFDate: issued, locale: de}";
+    assert!(!holds(&condition.replace("MATCH", "all")));
+    assert!(holds(&condition.replace("MATCH", "any")));
+    assert!(!holds(&condition.replace("MATCH", "none")));
+    assert!(holds("{isRefType: [book], isEDTFDate: issued, locale: en}"));
+}
+
+#[test]
+fn each_listed_variable_is_a_test() {
+    assert!(holds("{hasVariable: [author, issued]}"));
+    assert!(!holds("{hasVariable: [author, publisher]}"));
+    assert!(holds("{match: any, hasVariable: [publisher, author]}"));
+    assert!(!holds("{match: none, hasVariable: [publisher, author]}"));
+    assert!(holds("{match: none, hasVariable: [publisher, editor]}"));
+}
+
+#[test]
+fn no_tests_never_holds() {
+    assert!(!holds("{}"));
+    assert!(!holds("{match: none}"));
+    assert!(!holds("{match: any, hasVariable: []}"));
+}
@@ -0,0 +1,197 @@
+use csln_rs::bibliography::reference::Contributor;
+use csln_rs::locale::Locale;
+use csln_rs::processor::contributor::{format_names, label_role};
+use csln_rs::style::{ContributorListFormatting, ContributorRoles, RoleOption};
+
+fn contributors(names: &[&str]) -> Vec<Contributor> {
+    names
+        .iter()
+        .map(|name| Contributor {
+            name: Some(name.to_string()),
+            ..Contributor::default()
+        })
+        .collect()
+}
+
+fn format(names: &[&str], options: &str) -> String {
+    let options: ContributorListFormatting = serde_json::from_str(options).unwrap();
+    format_names(&contributors(names), Some(&options)).unwrap()
+}
+
+const DOE_JOHNSON_SMITH: &[&str] = &["Doe", "Johnson", "Smith"];
+const DOE_SMITH_WILLIAMS: &[&str] = &["J. Doe", "S. Smith", "T. Williams"];
+const DOE_WILLIAMS: &[&str] = &["J. Doe", "T. Williams"];
+
+#[test]
+fn and_as_text() {
+    assert_eq!(
+        format(DOE_JOHNSON_SMITH, r#"{"andAs": "text"}"#),
+        "Doe, Johnson and Smith"
+    );
+}
+
+#[test]
+fn and_as_symbol() {
+    assert_eq!(
+        format(DOE_JOHNSON_SMITH, r#"{"andAs": "symbol"}"#),
+        "Doe, Johnson & Smith"
+    );
+}
+
+#[test]
+fn at_al_short() {
+    let names = &["Doe", "Johnson", "Smith", "Williams"];
+    let options = r#"{"shorten": {"min": 3, "useFirst": 2, "atAl": "short"}}"#;
+    assert_eq!(format(names, options), "Doe, Johnson, et al.");
+}
+
+#[test]
+fn at_al_long() {
+    let names = &["Doe", "Johnson", "Smith", "Williams"];
+    let options = r#"{"shorten": {"min": 3, "useFirst": 2, "atAl": "long"}}"#;
+    assert_eq!(format(names, options), "Doe, Johnson, and others");
+}
+
+fn et_al(use_first: usize, precedes: &str, display_as_sort: &str) -> String {
+    let options = format!(
+        r#"{{"displayAsSort": "{}", "shorten": {{"min": 3, "useFirst": {}, "delimiterPrecedesEtAl": "{}"}}}}"#,
+        display_as_sort, use_first, precedes
+    );
+    format(DOE_SMITH_WILLIAMS, &options)
+}
+
+#[test]
+fn delimiter_precedes_et_al_contextual() {
+    assert_eq!(et_al(1, "contextual", "none"), "J. Doe et al.");
+    assert_eq!(et_al(2, "contextual", "none"), "J. Doe, S. Smith, et al.");
+}
+
+#[test]
+fn delimiter_precedes_et_al_after_inverted_name() {
+    assert_eq!(et_al(1, "after-inverted-name", "first"), "Doe, J., et al.");
+    assert_eq!(
+        et_al(2, "after-inverted-name", "first"),
+        "Doe, J., S. Smith et al."
+    );
+}
+
+#[test]
+fn delimiter_precedes_et_al_always() {
+    assert_eq!(et_al(1, "always", "none"), "J. Doe, et al.");
+    assert_eq!(et_al(2, "always", "none"), "J. Doe, S. Smith, et al.");
+}
+
+#[test]
+fn delimiter_precedes_et_al_never() {
+    assert_eq!(et_al(1, "never", "none"), "J. Doe et al.");
+    assert_eq!(et_al(2, "never", "none"), "J. Doe, S. Smith et al.");
+}
+
+fn last(names: &[&str], precedes: &str, display_as_sort: &str) -> String {
+    let options = format!(
+        r#"{{"displayAsSort": "{}", "shorten": {{"delimiterPrecedesLast": "{}"}}}}"#,
+        display_as_sort, precedes
+    );
+    format(names, &options)
+}
+
+#[test]
+fn delimiter_precedes_last_contextual() {
+    assert_eq!(
+        last(DOE_WILLIAMS, "contextual", "none"),
+        "J. Doe and T. Williams"
+    );
+    assert_eq!(
+        last(DOE_SMITH_WILLIAMS, "contextual", "none"),
+        "J. Doe, S. Smith, and T. Williams"
+    );
+}
+
+#[test]
+fn delimiter_precedes_last_after_inverted_name() {
+    assert_eq!(
+        last(DOE_WILLIAMS, "after-inverted-name", "first"),
+        "Doe, J., and T. Williams"
+    );
+    assert_eq!(
+        last(DOE_SMITH_WILLIAMS, "after-inverted-name", "first"),
+        "Doe, J., S. Smith and T. Williams"
+    );
+}
+
+#[test]
+fn delimiter_precedes_last_always() {
+    assert_eq!(
+        last(DOE_WILLIAMS, "always", "none"),
+        "J. Doe, and T. Williams"
+    );
+    assert_eq!(
+        last(DOE_SMITH_WILLIAMS, "always", "none"),
+        "J. Doe, S. Smith, and T. Williams"
+    );
+}
+
+#[test]
+fn delimiter_precedes_last_never() {
+    assert_eq!(
+        last(DOE_WILLIAMS, "never", "none"),
+        "J. Doe and T. Williams"
+    );
+    assert_eq!(
+        last(DOE_SMITH_WILLIAMS, "never", "none"),
+        "J. Doe, S. Smith and T. Williams"
+    );
+}
+
+#[test]
+fn display_as_sort_all() {
+    assert_eq!(
+        format(DOE_WILLIAMS, r#"{"displayAsSort": "all"}"#),
+        "Doe, J. and Williams, T."
+    );
+}
+
+fn role(options: &str, role: &ContributorRoles) -> String {
+    let options: RoleOption = serde_json::from_str(options).unwrap();
+    label_role(
+        "Jane Smith".to_string(),
+        role,
+        role,
+        false,
+        Some(&options),
+        &Locale::default(),
+    )
+}
+
+#[test]
+fn role_form_long() {
+    let editor = &ContributorRoles::Editor;
+    assert_eq!(role(r#"{"form": "long"}"#, editor), "Jane Smith (editor)");
+}
+
+#[test]
+fn role_form_short() {
+    let editor = &ContributorRoles::Editor;
+    assert_eq!(role(r#"{"form": "short"}"#, editor), "Jane Smith (ed.)");
+}
+
+#[test]
+fn role_form_verb() {
+    let editor = &ContributorRoles::Editor;
+    assert_eq!(role(r#"{"form": "verb"}"#, editor), "edited by Jane Smith");
+}
+
+#[test]
+fn role_form_verb_short() {
+    let editor = &ContributorRoles::Editor;
+    assert_eq!(role(r#"{"form": "verb-short"}"#, editor), "ed. Jane Smith");
+}
+
+#[test]
+fn role_omit_defaults_to_author() {
+    let author = &ContributorRoles::Author;
+    assert_eq!(role(r#"{"form": "long"}"#, author), "Jane Smith");
+    let translator = &ContributorRoles::Translator;
+    let options = r#"{"form": "short", "omit": ["translator"]}"#;
+    assert_eq!(role(options, translator), "Jane Smith");
+}
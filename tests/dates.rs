@@ -0,0 +1,211 @@
+use csln_rs::bibliography::date::{Bound, Date, Edtf, Season};
+use csln_rs::locale::{Locale, Locales, LocalizationTerms};
+use csln_rs::processor::date::format_date;
+use csln_rs::style::DateFormatting;
+
+fn date(value: &str) -> Date {
+    match value.parse::<Edtf>().unwrap() {
+        Edtf::Date(date) => date,
+        Edtf::Interval(..) => panic!("{} is an interval", value),
+    }
+}
+
+#[test]
+fn calendar_dates() {
+    let parsed = date("2020-05-17");
+    assert_eq!(
+        (parsed.year, parsed.month, parsed.day),
+        (2020, Some(5), Some(17))
+    );
+    assert_eq!(date("2020-05").day, None);
+    assert_eq!(date("2020").month, None);
+}
+
+#[test]
+fn date_and_time() {
+    let time = date("2020-05-17T10:30:00+01:00").time.unwrap();
+    assert_eq!((time.hour, time.minute, time.offset), (10, 30, Some(60)));
+    assert_eq!(date("2020-05-17T10:30:00Z").time.unwrap().offset, Some(0));
+}
+
+#[test]
+fn seasons() {
+    assert_eq!(date("2020-21").season, Some(Season::Spring));
+    assert_eq!(date("2020-24").season, Some(Season::Winter));
+}
+
+#[test]
+fn qualifiers() {
+    let uncertain = date("2020?");
+    assert!(uncertain.uncertain && !uncertain.approximate);
+    let approximate = date("2020-05~");
+    assert!(!approximate.uncertain && approximate.approximate);
+    let both = date("2020%");
+    assert!(both.uncertain && both.approximate);
+}
+
+#[test]
+fn unspecified_digits() {
+    let decade = date("201X");
+    assert_eq!((decade.year, decade.unspecified_digits), (2010, 1));
+    assert_eq!(date("1985-04-XX").day, None);
+}
+
+#[test]
+fn bce_and_long_years() {
+    assert_eq!(date("-0044").year, -44);
+    assert_eq!(date("Y-170000").year, -170000);
+    assert_eq!(date("Y170000002").year, 170000002);
+}
+
+#[test]
+fn intervals() {
+    let closed: Edtf = "2019/2021-06".parse().unwrap();
+    assert_eq!(closed.year(), Some(2019));
+    assert!(matches!(
+        closed,
+        Edtf::Interval(Bound::Date(_), Bound::Date(_))
+    ));
+    let open: Edtf = "2019/..".parse().unwrap();
+    assert!(matches!(open, Edtf::Interval(Bound::Date(_), Bound::Open)));
+    let unknown: Edtf = "/2021".parse().unwrap();
+    assert_eq!(unknown.year(), Some(2021));
+}
+
+#[test]
+fn invalid_dates() {
+    for value in [
+        "",
+        "20",
+        "20200",
+        "2020-13",
+        "2020-02-30",
+        "2020-05-",
+        "May 2020",
+        "../..",
+        "201X-05",
+    ] {
+        assert!(value.parse::<Edtf>().is_err(), "{}", value);
+    }
+    assert!("2020-02-29".parse::<Edtf>().is_ok());
+}
+
+fn format(value: &str, options: &str) -> String {
+    let options: DateFormatting = serde_json::from_str(options).unwrap();
+    format_date(
+        &value.parse().unwrap(),
+        Some(&options),
+        None,
+        &Locale::default(),
+    )
+}
+
+fn format_in(value: &str, options: &str, tag: &str) -> String {
+    let options: DateFormatting = serde_json::from_str(options).unwrap();
+    let locales = Locales::new();
+    format_date(
+        &value.parse().unwrap(),
+        Some(&options),
+        None,
+        &locales.locale(tag),
+    )
+}
+
+#[test]
+fn month_styles() {
+    assert_eq!(format("2020-09-05", "{}"), "September 5, 2020");
+    assert_eq!(
+        format("2020-09-05", r#"{"date": "medium"}"#),
+        "Sept. 5, 2020"
+    );
+    assert_eq!(format("2020-09-05", r#"{"date": "short"}"#), "9/5/2020");
+    assert_eq!(
+        format("2020-09-05", r#"{"month": "2-digit", "year": "2-digit"}"#),
+        "09/05/20"
+    );
+}
+
+#[test]
+fn ranges() {
+    assert_eq!(format("2019/2021", "{}"), "2019–2021");
+    assert_eq!(format("2020-05/2020-06", "{}"), "May–June 2020");
+    assert_eq!(format("2020-05-05/2020-05-17", "{}"), "May 5–17, 2020");
+    assert_eq!(
+        format("2019-12/2020-01", "{}"),
+        "December 2019–January 2020"
+    );
+}
+
+#[test]
+fn eras_and_qualifiers() {
+    assert_eq!(format("-0044", "{}"), "45 BC");
+    assert_eq!(format("0800", "{}"), "800 AD");
+    assert_eq!(format("2020~", "{}"), "circa 2020");
+    assert_eq!(format("2020?", "{}"), "2020?");
+}
+
+#[test]
+fn localized_day_ranges() {
+    assert_eq!(
+        format_in("2020-05-05/2020-05-07", "{}", "de-DE"),
+        "5.–7. Mai 2020"
+    );
+    assert_eq!(
+        format_in("2020-05-05/2020-05-07", "{}", "fr-FR"),
+        "5–7 mai 2020"
+    );
+    assert_eq!(
+        format_in("2020-05-05/2020-05-07", "{}", "ja-JP"),
+        "2020年5月5日–7日"
+    );
+}
+
+#[test]
+fn localized_times() {
+    let value = "2020-05-17T14:05:09+01:00";
+    assert_eq!(
+        format(value, r#"{"time": "short"}"#),
+        "May 17, 2020 2:05 PM"
+    );
+    assert_eq!(
+        format(value, r#"{"time": "long"}"#),
+        "May 17, 2020 2:05:09 PM UTC+01:00"
+    );
+    assert_eq!(
+        format_in(value, r#"{"time": "medium"}"#, "de-DE"),
+        "17. Mai 2020 14:05:09"
+    );
+    assert_eq!(
+        format_in("2020-05-17T09:30:00", r#"{"time": "short"}"#, "en-GB"),
+        "17 May 2020 09:30"
+    );
+}
+
+#[test]
+fn localized_twelve_hour_times() {
+    let mut locales = Locales::new();
+    let terms: LocalizationTerms = serde_yaml::from_str(
+        r#"
+locale: ko-KR
+dates:
+  numericDate: "{year}. {month}. {day}."
+  time: "{period} {hour}:{minute}:{second}"
+  am: 오전
+  pm: 오후
+"#,
+    )
+    .unwrap();
+    locales.insert(terms);
+    let format = |value: &str| {
+        let options: DateFormatting =
+            serde_json::from_str(r#"{"date": "short", "time": "medium"}"#).unwrap();
+        format_date(
+            &value.parse().unwrap(),
+            Some(&options),
+            None,
+            &locales.locale("ko-KR"),
+        )
+    };
+    assert_eq!(format("2020-05-17T14:05:09"), "2020. 5. 17. 오후 2:05:09");
+    assert_eq!(format("2020-05-17T00:30:00"), "2020. 5. 17. 오전 12:30:00");
+}
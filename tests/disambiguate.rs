@@ -0,0 +1,145 @@
+use csln_rs::bibliography::InputBibliography;
+use csln_rs::citation::Citation;
+use csln_rs::processor::Processor;
+use csln_rs::style::Style;
+
+const BIBLIOGRAPHY: &str = r#"
+etal1: {title: A, author: [{name: "Jane Doe"}, {name: "Rick Roe"}, {name: "Ed Poe"}], issued: "2019"}
+etal2: {title: B, author: [{name: "Jane Doe"}, {name: "Al Moe"}, {name: "Ed Poe"}], issued: "2019"}
+primary1: {title: C, author: [{name: "Jane Doe"}], issued: "2020"}
+primary2: {title: D, author: [{name: "Ann Doe"}], issued: "2020"}
+all1: {title: E, author: [{name: "Jane Doe"}, {name: "Al Roe"}], issued: "2021"}
+all2: {title: F, author: [{name: "Jane Doe"}, {name: "Bo Roe"}], issued: "2021"}
+full1: {title: G, author: [{name: "Jane Doe"}, {name: "Al Roe"}], issued: "2022"}
+full2: {title: H, author: [{name: "Jane Doe"}, {name: "Alice Roe"}], issued: "2022"}
+"#;
+
+/// The citation of a pair of references, disambiguated per the options.
+fn cite(disambiguate: &str, ids: [&str; 2]) -> String {
+    let style: Style = serde_yaml::from_str(&format!(
+        r#"
+options:
+  contributors:
+    shorten: {{min: 3, useFirst: 1}}
+  disambiguate: {}
+citation:
+  template:
+    - contributor: author
+      format: short
+    - date: issued
+      format: year
+"#,
+        disambiguate
+    ))
+    .unwrap();
+    let bibliography: InputBibliography = serde_yaml::from_str(BIBLIOGRAPHY).unwrap();
+    let citation: Citation = serde_yaml::from_str(&format!(
+        "references: [{{refID: {}}}, {{refID: {}}}]",
+        ids[0], ids[1]
+    ))
+    .unwrap();
+    let citations = [citation];
+    Processor::new(&style, &bibliography)
+        .with_citations(&citations)
+        .render_citation(&citations[0])
+        .unwrap()
+}
+
+#[test]
+fn add_names_before_given_names() {
+    assert_eq!(
+        cite("{addNames: primary}", ["etal1", "etal2"]),
+        "(Doe, Roe, et al., 2019; Doe, Moe, et al., 2019)"
+    );
+}
+
+#[test]
+fn add_names_primary() {
+    assert_eq!(
+        cite("{addNames: primary}", ["primary1", "primary2"]),
+        "(Jane Doe, 2020; Ann Doe, 2020)"
+    );
+    assert_eq!(
+        cite(
+            "{addNames: primary-with-initials}",
+            ["primary1", "primary2"]
+        ),
+        "(J. Doe, 2020; A. Doe, 2020)"
+    );
+    assert_eq!(
+        cite("{addNames: primary}", ["all1", "all2"]),
+        "(Doe and Roe, 2021; Doe and Roe, 2021)"
+    );
+}
+
+#[test]
+fn add_names_all() {
+    assert_eq!(
+        cite("{addNames: all-with-initials}", ["all1", "all2"]),
+        "(J. Doe and A. Roe, 2021; J. Doe and B. Roe, 2021)"
+    );
+    assert_eq!(
+        cite("{addNames: all-with-initials}", ["full1", "full2"]),
+        "(Doe and Roe, 2022; Doe and Roe, 2022)"
+    );
+    assert_eq!(
+        cite("{addNames: all}", ["full1", "full2"]),
+        "(Jane Doe and Al Roe, 2022; Jane Doe and Alice Roe, 2022)"
+    );
+}
+
+#[test]
+fn add_names_by_cite() {
+    assert_eq!(
+        cite("{addNames: by-cite}", ["all1", "all2"]),
+        "(J. Doe and A. Roe, 2021; J. Doe and B. Roe, 2021)"
+    );
+    assert_eq!(
+        cite("{addNames: by-cite}", ["full1", "full2"]),
+        "(Jane Doe and Al Roe, 2022; Jane Doe and Alice Roe, 2022)"
+    );
+}
+
+#[test]
+fn year_suffixes_follow_bibliography_order() {
+    let style: Style = serde_yaml::from_str(
+        r#"
+options:
+  disambiguate: {addYearSuffix: true}
+  sort: [{key: title}]
+bibliography:
+  template:
+    - title: title
+    - date: issued
+      format: year
+citation:
+  template:
+    - contributor: author
+    - date: issued
+      format: year
+"#,
+    )
+    .unwrap();
+    let bibliography: InputBibliography = (0..28)
+        .map(|index| {
+            let reference = format!(
+                "{{title: T{:02}, author: [{{name: Doe}}], issued: \"2020\"}}",
+                27 - index
+            );
+            (
+                format!("ref{}", index),
+                serde_yaml::from_str(&reference).unwrap(),
+            )
+        })
+        .collect();
+    let entries: Vec<String> = Processor::new(&style, &bibliography)
+        .render_bibliography()
+        .into_iter()
+        .map(|entry| entry.value)
+        .collect();
+    assert_eq!(entries[0], "T00. 2020a.");
+    assert_eq!(entries[1], "T01. 2020b.");
+    assert_eq!(entries[25], "T25. 2020z.");
+    assert_eq!(entries[26], "T26. 2020aa.");
+    assert_eq!(entries[27], "T27. 2020ab.");
+}
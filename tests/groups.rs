@@ -0,0 +1,103 @@
+use csln_rs::bibliography::InputBibliography;
+use csln_rs::processor::Processor;
+use csln_rs::style::Style;
+
+const BIBLIOGRAPHY: &str = r#"
+a: {title: A, author: [{name: "Ann Roe"}], issued: "2020"}
+b: {title: B, author: [{name: "Bo Doe"}], issued: "2018"}
+c: {title: C, author: [{name: "Cy Doe"}], issued: "2019"}
+d: {title: D, author: [{name: "Di Moe"}], issued: "2018"}
+e: {title: E, author: [{name: "Ed Doe"}]}
+"#;
+
+/// The headings and entries of the grouped bibliography per the options.
+fn groups(options: &str) -> Vec<(String, Vec<String>)> {
+    let style: Style = serde_yaml::from_str(&format!(
+        r#"
+options:
+{}
+bibliography:
+  template:
+    - title: title
+"#,
+        options
+    ))
+    .unwrap();
+    let bibliography: InputBibliography = serde_yaml::from_str(BIBLIOGRAPHY).unwrap();
+    Processor::new(&style, &bibliography)
+        .render_grouped_bibliography()
+        .into_iter()
+        .map(|group| {
+            let entries = group.entries.into_iter().map(|entry| entry.value).collect();
+            (group.heading.unwrap_or_default(), entries)
+        })
+        .collect()
+}
+
+fn headings(options: &str) -> Vec<String> {
+    groups(options)
+        .into_iter()
+        .map(|(heading, _)| heading)
+        .collect()
+}
+
+#[test]
+fn groups_are_ordered_by_their_keys() {
+    assert_eq!(
+        groups("  group: [year]\n  sort: [{key: author}]"),
+        [
+            ("2018".to_string(), vec!["B.".to_string(), "D.".to_string()]),
+            ("2019".to_string(), vec!["C.".to_string()]),
+            ("2020".to_string(), vec!["A.".to_string()]),
+            ("no date".to_string(), vec!["E.".to_string()]),
+        ]
+    );
+    assert_eq!(
+        headings("  group: [author]\n  sort: [{key: year}]"),
+        ["Bo Doe", "Cy Doe", "Ed Doe", "Di Moe", "Ann Roe"]
+    );
+}
+
+#[test]
+fn groups_follow_the_sort_order_of_their_key() {
+    assert_eq!(
+        headings("  group: [year]\n  sort: [{key: year, order: descending}]"),
+        ["2020", "2019", "2018", "no date"]
+    );
+}
+
+#[test]
+fn group_heading_delimiters() {
+    assert_eq!(
+        headings("  group: [{key: year, delimiter: \" – \"}, author]\n  sort: [{key: title}]"),
+        [
+            "2018 – Bo Doe",
+            "2018 – Di Moe",
+            "2019 – Cy Doe",
+            "2020 – Ann Roe",
+            "no date – Ed Doe"
+        ]
+    );
+    assert_eq!(
+        headings("  group: [year, author]\n  sort: [{key: title}]")[0],
+        "2018, Bo Doe"
+    );
+}
+
+#[test]
+fn early_year_headings() {
+    let style: Style = serde_yaml::from_str(
+        "options:\n  group: [year]\nbibliography:\n  template:\n    - title: title",
+    )
+    .unwrap();
+    let bibliography: InputBibliography = serde_yaml::from_str(
+        "{caesar: {title: Gallic War, issued: \"-0044\"}, bede: {title: History, issued: \"0731\"}}",
+    )
+    .unwrap();
+    let headings: Vec<String> = Processor::new(&style, &bibliography)
+        .render_grouped_bibliography()
+        .into_iter()
+        .filter_map(|group| group.heading)
+        .collect();
+    assert_eq!(headings, ["45 BC", "731 AD"]);
+}
@@ -0,0 +1,167 @@
+use std::fs;
+use std::path::PathBuf;
+
+use csln_rs::bibliography::InputBibliography;
+use csln_rs::locale::{LocaleError, Locales, LocalizationTerms, LocalizedTermFormat};
+use csln_rs::processor::Processor;
+use csln_rs::style::{ContributorRoles, Form, LocalizedTermName, Style};
+
+#[test]
+fn built_in_terms() {
+    let locales = Locales::new();
+    let de = locales.locale("de-DE");
+    assert_eq!(de.term(&LocalizedTermName::And, None, false), "und");
+    assert_eq!(
+        de.role(&ContributorRoles::Editor, &Form::Short, false),
+        "Hrsg."
+    );
+    let fr = locales.locale("fr-FR");
+    assert_eq!(fr.month(2, true), "févr.");
+}
+
+#[test]
+fn forms_and_plurals_fall_back() {
+    let locales = Locales::new();
+    let en = locales.locale("en-US");
+    let page = LocalizedTermName::Page;
+    assert_eq!(
+        en.term(&page, Some(LocalizedTermFormat::Short), true),
+        "pp."
+    );
+    assert_eq!(
+        en.term(&page, Some(LocalizedTermFormat::Symbol), false),
+        "p."
+    );
+    assert_eq!(en.term(&LocalizedTermName::EtAl, None, true), "et al.");
+}
+
+#[test]
+fn regional_locales_fall_back_to_base_language_then_en_us() {
+    let locales = Locales::new();
+    let de_at = locales.locale("de-AT");
+    assert_eq!(de_at.term(&LocalizedTermName::EtAl, None, false), "u. a.");
+    let unknown = locales.locale("xx-YY");
+    assert_eq!(
+        unknown.term(&LocalizedTermName::EtAl, None, false),
+        "et al."
+    );
+}
+
+#[test]
+fn languages_fall_back_to_their_preferred_region() {
+    let locales = Locales::new();
+    for tag in ["en", "en-CA", "en-AU"] {
+        let en = locales.locale(tag);
+        assert_eq!(en.tag(), "en-US");
+        assert!(en.punctuation_in_quote());
+        assert_eq!(en.term(&LocalizedTermName::OpenQuote, None, false), "“");
+    }
+    assert_eq!(locales.locale("en-GB").tag(), "en-GB");
+    assert_eq!(locales.locale("de-CH").tag(), "de-DE");
+    assert_eq!(locales.locale("zh-TW").tag(), "zh-CN");
+}
+
+#[test]
+fn locale_files_override_built_in_terms() {
+    let mut locales = Locales::new();
+    let terms: LocalizationTerms =
+        serde_yaml::from_str("locale: de-DE\nterms:\n  and: {single: sowie}\n").unwrap();
+    locales.insert(terms);
+    let de = locales.locale("de-DE");
+    assert_eq!(de.term(&LocalizedTermName::And, None, false), "sowie");
+    assert_eq!(de.term(&LocalizedTermName::EtAl, None, false), "u. a.");
+}
+
+/// A new, empty directory for the locale files of a test.
+fn locale_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("csln-rs-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+#[test]
+fn load_locale_directory() {
+    let dir = locale_dir("locales");
+    fs::write(
+        dir.join("de.json"),
+        r#"{"locale": "de-DE", "terms": {"and": {"single": "sowie"}}}"#,
+    )
+    .unwrap();
+    fs::write(
+        dir.join("fr.yaml"),
+        "locale: fr-FR\nterms:\n  et-al: {single: et coll.}\n",
+    )
+    .unwrap();
+    fs::write(dir.join("notes.txt"), "not a locale").unwrap();
+    let mut locales = Locales::new();
+    locales.load_dir(&dir).unwrap();
+    let de = locales.locale("de-DE");
+    assert_eq!(de.term(&LocalizedTermName::And, None, false), "sowie");
+    assert_eq!(de.term(&LocalizedTermName::EtAl, None, false), "u. a.");
+    let fr = locales.locale("fr-FR");
+    assert_eq!(fr.term(&LocalizedTermName::EtAl, None, false), "et coll.");
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn malformed_locale_files_are_errors() {
+    let dir = locale_dir("malformed");
+    let path = dir.join("broken.yaml");
+    fs::write(&path, "locale: [de-DE\n").unwrap();
+    let mut locales = Locales::new();
+    match locales.load_dir(&dir) {
+        Err(LocaleError::Parse(error_path, _)) => assert_eq!(error_path, path),
+        other => panic!("expected a parse error, got {:?}", other.map(|_| ())),
+    }
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+const MIXED_BIBLIOGRAPHY: &str = r#"
+a: {title: A, language: de-AT, author: [{name: "Jane Doe"}, {name: "Rick Roe"}], issued: "2020-05-17"}
+b: {title: B, author: [{name: "Ed Poe"}, {name: "Al Low"}], issued: "2020-03-02"}
+"#;
+
+fn render(scope: &str) -> Vec<String> {
+    let style: Style = serde_yaml::from_str(&format!(
+        "options: {{localization: {{scope: {}}}}}\nbibliography:\n  template:\n    - contributor: author\n    - date: issued\n    - when: [{{locale: de, template: [{{text: deutsch}}]}}]\n",
+        scope
+    ))
+    .unwrap();
+    let bibliography: InputBibliography = serde_yaml::from_str(MIXED_BIBLIOGRAPHY).unwrap();
+    let locales = Locales::new();
+    let processor = Processor::new(&style, &bibliography).with_locales(&locales, "en-US");
+    processor
+        .render_bibliography()
+        .into_iter()
+        .map(|entry| entry.value)
+        .collect()
+}
+
+#[test]
+fn per_item_scope_renders_references_in_their_language() {
+    assert_eq!(
+        render("per-item"),
+        [
+            "Jane Doe und Rick Roe. 17. Mai 2020. deutsch.",
+            "Ed Poe and Al Low. March 2, 2020."
+        ]
+    );
+    assert_eq!(
+        render("global"),
+        [
+            "Jane Doe and Rick Roe. May 17, 2020. deutsch.",
+            "Ed Poe and Al Low. March 2, 2020."
+        ]
+    );
+}
+
+#[test]
+fn unknown_terms_are_rejected() {
+    let terms: Result<LocalizationTerms, _> =
+        serde_yaml::from_str("{locale: de-DE, terms: {pgae: {single: Seite}}}");
+    assert!(terms.is_err());
+    let style: Result<Style, _> =
+        serde_yaml::from_str("bibliography:\n  template:\n    - term: pgae");
+    assert!(style.is_err());
+}
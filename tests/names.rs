@@ -0,0 +1,149 @@
+use csln_rs::bibliography::name::Name;
+use csln_rs::bibliography::reference::Contributor;
+use csln_rs::locale::{Locales, LocalizationTerms};
+use csln_rs::processor::contributor::{format_name_list, format_names, NameList};
+use csln_rs::style::ContributorListFormatting;
+
+fn name(family: &str, given: &str, particle: &str, suffix: &str) -> Name {
+    let part = |value: &str| (!value.is_empty()).then(|| value.to_string());
+    Name {
+        family: part(family),
+        given: part(given),
+        non_dropping_particle: part(particle),
+        suffix: part(suffix),
+        ..Name::default()
+    }
+}
+
+fn format(contributors: &str, display_as_sort: &str) -> String {
+    let options = format!(r#"{{"displayAsSort": "{}"}}"#, display_as_sort);
+    format_with(contributors, &options)
+}
+
+fn format_with(contributors: &str, options: &str) -> String {
+    let contributors: Vec<Contributor> = serde_yaml::from_str(contributors).unwrap();
+    let options: ContributorListFormatting = serde_json::from_str(options).unwrap();
+    format_names(&contributors, Some(&options)).unwrap()
+}
+
+#[test]
+fn parse_sort_and_display_order() {
+    let berg = name("Berg", "Jan", "van der", "Jr.");
+    assert_eq!(Name::parse("van der Berg, Jan Jr."), berg);
+    assert_eq!(Name::parse("van der Berg, Jan, Jr."), berg);
+    assert_eq!(Name::parse("Jan van der Berg Jr."), berg);
+    assert_eq!(Name::parse("Jan van der Berg, Jr."), berg);
+    assert_eq!(Name::parse("Jane Ann Doe"), name("Doe", "Jane Ann", "", ""));
+    assert_eq!(Name::parse("Doe"), name("Doe", "", "", ""));
+    assert_eq!(Name::parse("van Berg"), name("Berg", "", "van", ""));
+    assert_eq!(Name::parse("Doe,, Jane"), name("Doe", "Jane", "", ""));
+    assert_eq!(Name::parse("bell hooks"), name("hooks", "bell", "", ""));
+}
+
+#[test]
+fn parse_dropping_particle() {
+    let beethoven = Name::parse("Beethoven, Ludwig van");
+    assert_eq!(beethoven.family.as_deref(), Some("Beethoven"));
+    assert_eq!(beethoven.given.as_deref(), Some("Ludwig"));
+    assert_eq!(beethoven.dropping_particle.as_deref(), Some("van"));
+}
+
+#[test]
+fn structured_names() {
+    let contributors = r#"
+- {family: Berg, given: Jan, non-dropping-particle: van der, suffix: Jr.}
+- {family: Beethoven, given: Ludwig, dropping-particle: van}
+- {name: "Doe, Jane"}
+"#;
+    assert_eq!(
+        format(contributors, "none"),
+        "Jan van der Berg Jr., Ludwig van Beethoven and Jane Doe"
+    );
+    assert_eq!(
+        format(contributors, "all"),
+        "van der Berg, Jan, Jr., Beethoven, Ludwig van and Doe, Jane"
+    );
+}
+
+#[test]
+fn literal_names() {
+    let contributors = r#"
+- {literal: "World Health Organization"}
+- {name: "Smith and Sons", parse: false}
+"#;
+    assert_eq!(
+        format(contributors, "all"),
+        "World Health Organization and Smith and Sons"
+    );
+}
+
+#[test]
+fn initialize_with() {
+    let contributors = r#"[{name: "Jane Ann Doe"}, {name: "J.R.R. Tolkien"}]"#;
+    assert_eq!(
+        format_with(contributors, r#"{"initializeWith": ". "}"#),
+        "J. A. Doe and J. R. R. Tolkien"
+    );
+    assert_eq!(
+        format_with(
+            contributors,
+            r#"{"initializeWith": ".", "displayAsSort": "all"}"#
+        ),
+        "Doe, J.A. and Tolkien, J.R.R."
+    );
+    assert_eq!(
+        format_with(contributors, r#"{"initializeWith": ""}"#),
+        "JA Doe and JRR Tolkien"
+    );
+}
+
+#[test]
+fn initialize_hyphenated_names() {
+    let contributors = r#"[{name: "Jean-Paul Sartre"}]"#;
+    assert_eq!(
+        format_with(contributors, r#"{"initializeWith": ". "}"#),
+        "J.-P. Sartre"
+    );
+    assert_eq!(
+        format_with(
+            contributors,
+            r#"{"initializeWith": ". ", "initializeWithHyphen": false}"#
+        ),
+        "J. P. Sartre"
+    );
+}
+
+#[test]
+fn initialize_locale_digraphs() {
+    let mut locales = Locales::new();
+    let terms: LocalizationTerms =
+        serde_yaml::from_str("{locale: hu-HU, initialDigraphs: [Cs, Gy, Sz, Dz, Dzs]}").unwrap();
+    locales.insert(terms);
+    let contributors: Vec<Contributor> =
+        serde_yaml::from_str(r#"[{name: "Gyula Szabó"}, {name: "Dzsenifer Kiss"}]"#).unwrap();
+    let options: ContributorListFormatting =
+        serde_json::from_str(r#"{"initializeWith": ". "}"#).unwrap();
+    let list = NameList {
+        locale: locales.locale("hu-HU"),
+        ..NameList::default()
+    };
+    assert_eq!(
+        format_name_list(&contributors, Some(&options), &list).unwrap(),
+        "Gy. Szabó and Dzs. Kiss"
+    );
+    assert_eq!(
+        format_names(&contributors, Some(&options)).unwrap(),
+        "G. Szabó and D. Kiss"
+    );
+}
+
+#[test]
+fn cjk_names_are_not_initialized() {
+    assert_eq!(
+        format_with(
+            "[{family: 毛, given: 泽东}, {family: Doe, given: Jane}]",
+            r#"{"initializeWith": ". ", "displayAsSort": "all"}"#
+        ),
+        "毛, 泽东 and Doe, J."
+    );
+}
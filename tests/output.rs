@@ -0,0 +1,143 @@
+use csln_rs::bibliography::InputBibliography;
+use csln_rs::output::{output_format, OutputFormat};
+use csln_rs::processor::Processor;
+use csln_rs::style::Style;
+
+const STYLE: &str = r#"
+bibliography:
+  template:
+    - contributor: author
+    - title: title
+      emph: true
+    - date: issued
+      bold: true
+      wrap: parentheses
+"#;
+
+const BIBLIOGRAPHY: &str = r#"
+doe: {title: "Cats & <Dogs>?", author: [{name: "Jane Doe"}, {name: "Rick Roe"}], issued: "2020"}
+"#;
+
+fn render(format: &str) -> String {
+    let style: Style = serde_yaml::from_str(STYLE).unwrap();
+    let bibliography: InputBibliography = serde_yaml::from_str(BIBLIOGRAPHY).unwrap();
+    let output: &dyn OutputFormat = output_format(format).unwrap();
+    let processor = Processor::new(&style, &bibliography).with_output(output);
+    processor.render_bibliography().remove(0).value
+}
+
+#[test]
+fn plain_text() {
+    assert_eq!(
+        render("plain"),
+        "Jane Doe and Rick Roe. Cats & <Dogs>? (2020)."
+    );
+}
+
+#[test]
+fn html() {
+    assert_eq!(
+        render("html"),
+        concat!(
+            r#"<div class="csl-entry"><span class="csl-author">Jane Doe and Rick Roe</span>. "#,
+            r#"<span class="csl-title"><i>Cats &amp; &lt;Dogs&gt;?</i></span> "#,
+            r#"<span class="csl-issued">(<b>2020</b>)</span>.</div>"#
+        )
+    );
+}
+
+#[test]
+fn markdown() {
+    assert_eq!(
+        render("markdown"),
+        r"Jane Doe and Rick Roe. *Cats & \<Dogs>?* (**2020**)."
+    );
+}
+
+#[test]
+fn latex() {
+    assert_eq!(
+        render("latex"),
+        r"Jane Doe and Rick Roe. \emph{Cats \& <Dogs>?} (\textbf{2020})."
+    );
+}
+
+#[test]
+fn latex_escaping() {
+    let latex = output_format("latex").unwrap();
+    assert_eq!(
+        latex.text(r"50% of $5 #1 a_b {x} ~ ^ \ 12–14"),
+        r"50\% of \$5 \#1 a\_b \{x\} \textasciitilde{} \textasciicircum{} \textbackslash{} 12--14"
+    );
+}
+
+#[test]
+fn rtf() {
+    assert_eq!(
+        render("rtf"),
+        r"{\pard Jane Doe and Rick Roe. {\i Cats & <Dogs>?} ({\b 2020}).\par}"
+    );
+    let rtf = output_format("rtf").unwrap();
+    assert_eq!(rtf.citation(&rtf.text("{Müller}")), r"\{M\u252?ller\}");
+}
+
+#[test]
+fn ooxml() {
+    let run = |text: &str, properties: &str| {
+        format!(
+            r#"<w:r>{}<w:t xml:space="preserve">{}</w:t></w:r>"#,
+            properties, text
+        )
+    };
+    assert_eq!(
+        render("ooxml"),
+        [
+            "<w:p>".to_string(),
+            run("Jane Doe and Rick Roe", ""),
+            run(". ", ""),
+            run("Cats &amp; &lt;Dogs&gt;?", "<w:rPr><w:i/></w:rPr>"),
+            run(" (", ""),
+            run("2020", "<w:rPr><w:b/></w:rPr>"),
+            run(").", ""),
+            "</w:p>".to_string(),
+        ]
+        .concat()
+    );
+}
+
+#[test]
+fn documents() {
+    let rtf = output_format("rtf").unwrap();
+    assert_eq!(
+        rtf.document(&rtf.paragraph(&rtf.citation("(Doe, 2020)"))),
+        "{\\rtf1\\ansi\\deff0\n{\\pard (Doe, 2020)\\par}\n}"
+    );
+    let ooxml = output_format("ooxml").unwrap();
+    let document = ooxml.document(&ooxml.paragraph(&ooxml.citation("(Doe, 2020)")));
+    assert!(document.starts_with(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"yes\"?>\n<w:document "
+    ));
+    assert!(document.ends_with(
+        "<w:body>\n<w:p><w:r><w:t xml:space=\"preserve\">(Doe, 2020)</w:t></w:r></w:p>\n</w:body></w:document>"
+    ));
+    let html = output_format("html").unwrap();
+    assert_eq!(html.document(&html.paragraph("(Doe, 2020)")), "(Doe, 2020)");
+}
+
+#[test]
+fn hanging_indent() {
+    let style: Style = serde_yaml::from_str(&format!(
+        "bibliography:\n  listStyle: hanging-indent\n{}",
+        STYLE.trim_start_matches("\nbibliography:\n")
+    ))
+    .unwrap();
+    let bibliography: InputBibliography = serde_yaml::from_str(BIBLIOGRAPHY).unwrap();
+    let processor =
+        Processor::new(&style, &bibliography).with_output(output_format("rtf").unwrap());
+    let entry = processor.render_bibliography().remove(0).value;
+    assert!(
+        entry.starts_with(r"{\pard\li720\fi-720 Jane Doe"),
+        "{}",
+        entry
+    );
+}
@@ -0,0 +1,120 @@
+use csln_rs::bibliography::InputBibliography;
+use csln_rs::locale::Locales;
+use csln_rs::output::{Html, Markdown, Plain};
+use csln_rs::processor::punctuation::{curl_quotes, punctuate, quote, range_dashes};
+use csln_rs::processor::Processor;
+use csln_rs::style::Style;
+
+#[test]
+fn quotation_marks_per_locale() {
+    let locales = Locales::new();
+    assert_eq!(quote("Title", &locales.locale("en-US")), "“Title”");
+    assert_eq!(quote("Title", &locales.locale("en-GB")), "‘Title’");
+    assert_eq!(quote("Titel", &locales.locale("de-DE")), "„Titel“");
+    assert_eq!(
+        quote("Titre", &locales.locale("fr-FR")),
+        "«\u{a0}Titre\u{a0}»"
+    );
+}
+
+#[test]
+fn nested_and_straight_quotes_become_inner_marks() {
+    let locales = Locales::new();
+    let en_us = locales.locale("en-US");
+    assert_eq!(quote("On “Hamlet”", &en_us), "“On ‘Hamlet’”");
+    let de_de = locales.locale("de-DE");
+    assert_eq!(quote("„Faust“ und mehr", &de_de), "„‚Faust‘ und mehr“");
+    let straight = curl_quotes(r#"On "Hamlet" (1603)"#, &en_us);
+    assert_eq!(straight, "On “Hamlet” (1603)");
+    assert_eq!(quote(&straight, &en_us), "“On ‘Hamlet’ (1603)”");
+    assert_eq!(
+        curl_quotes(r#""Faust" und mehr"#, &de_de),
+        "„Faust“ und mehr"
+    );
+}
+
+#[test]
+fn punctuation_moves_inside_quotes() {
+    let locales = Locales::new();
+    let en_us = locales.locale("en-US");
+    assert_eq!(punctuate("“Title”", ". ", &en_us, &Plain), "“Title.” ");
+    assert_eq!(punctuate("“Title”", ", ", &en_us, &Plain), "“Title,” ");
+}
+
+#[test]
+fn punctuation_stays_outside_quotes() {
+    let locales = Locales::new();
+    assert_eq!(
+        punctuate("‘Title’", ". ", &locales.locale("en-GB"), &Plain),
+        "‘Title’. "
+    );
+    assert_eq!(
+        punctuate(
+            "«\u{a0}Titre\u{a0}»",
+            ", ",
+            &locales.locale("fr-FR"),
+            &Plain
+        ),
+        "«\u{a0}Titre\u{a0}», "
+    );
+}
+
+#[test]
+fn duplicate_punctuation_collapses() {
+    let locales = Locales::new();
+    let (en_us, en_gb) = (locales.locale("en-US"), locales.locale("en-GB"));
+    assert_eq!(punctuate("Title?", ". ", &en_gb, &Plain), "Title? ");
+    assert_eq!(punctuate("Doe et al.", ". ", &en_gb, &Plain), "Doe et al. ");
+    assert_eq!(punctuate("“Title?”", ". ", &en_us, &Plain), "“Title?” ");
+    assert_eq!(punctuate("‘Title?’", ".", &en_gb, &Plain), "‘Title?’");
+    assert_eq!(punctuate("Doe,", ", ", &en_gb, &Plain), "Doe, ");
+    assert_eq!(
+        punctuate("Doe et al.", ", ", &en_gb, &Plain),
+        "Doe et al., "
+    );
+}
+
+#[test]
+fn punctuation_passes_closing_markup() {
+    let locales = Locales::new();
+    let en_us = locales.locale("en-US");
+    assert_eq!(
+        punctuate("<span>“<i>Title</i>”</span>", ". ", &en_us, &Html),
+        "<span>“<i>Title</i>.”</span> "
+    );
+    assert_eq!(punctuate("*Why?*", ". ", &en_us, &Markdown), "*Why?* ");
+}
+
+#[test]
+fn punctuation_within_values_is_kept() {
+    let style: Style = serde_yaml::from_str(
+        "bibliography:\n  template:\n    - title: title\n    - contributor: author\n    - variable: url\n",
+    )
+    .unwrap();
+    let bibliography: InputBibliography = serde_yaml::from_str(
+        r#"
+a: {title: "Wait... what?", author: [{name: "Jane Doe"}], url: "https://example.org/a..b"}
+b: {title: "And Then...", url: "https://example.org/b."}
+"#,
+    )
+    .unwrap();
+    let mut entries: Vec<String> = Processor::new(&style, &bibliography)
+        .render_bibliography()
+        .into_iter()
+        .map(|entry| entry.value)
+        .collect();
+    entries.sort();
+    assert_eq!(
+        entries,
+        [
+            "And Then... https://example.org/b.",
+            "Wait... what? Jane Doe. https://example.org/a..b."
+        ]
+    );
+}
+
+#[test]
+fn numeric_ranges_take_en_dashes() {
+    assert_eq!(range_dashes("12-14"), "12–14");
+    assert_eq!(range_dashes("xii-xiv, 3-5"), "xii-xiv, 3–5");
+}
@@ -0,0 +1,125 @@
+use csln_rs::bibliography::InputBibliography;
+use csln_rs::processor::Processor;
+use csln_rs::style::{ReferenceTypes, Style};
+
+const BIBLIOGRAPHY: &str = r#"
+report:
+  type: report
+  title: Annual Report
+  issued: "2020"
+dataset:
+  type: dataset
+  title: Survey Data
+  issued: "2021"
+paper:
+  type: paper-conference
+  title: A Talk
+  issued: "2022"
+"#;
+
+#[test]
+fn is_ref_type() {
+    let style: Style = serde_yaml::from_str(
+        r#"
+bibliography:
+  template:
+    - title: title
+    - when:
+        - isRefType: [report, dataset]
+          template: [{text: data}]
+      else:
+        template: [{text: other}]
+"#,
+    )
+    .unwrap();
+    let bibliography: InputBibliography = serde_yaml::from_str(BIBLIOGRAPHY).unwrap();
+    let mut entries: Vec<String> = Processor::new(&style, &bibliography)
+        .render_bibliography()
+        .into_iter()
+        .map(|entry| entry.value)
+        .collect();
+    entries.sort();
+    assert_eq!(
+        entries,
+        [
+            "A Talk. other.",
+            "Annual Report. data.",
+            "Survey Data. data."
+        ]
+    );
+}
+
+#[test]
+fn csl_type_names() {
+    let types: Vec<ReferenceTypes> =
+        serde_yaml::from_str("[article-journal, paper-conference, legislation, song]").unwrap();
+    assert_eq!(
+        types,
+        [
+            ReferenceTypes::Article,
+            ReferenceTypes::ConferencePaper,
+            ReferenceTypes::Statute,
+            ReferenceTypes::AudioRecording,
+        ]
+    );
+    let types: Vec<ReferenceTypes> =
+        serde_yaml::from_str("[legal-case, podcast-episode, working-paper]").unwrap();
+    assert_eq!(
+        types,
+        [
+            ReferenceTypes::LegalCase,
+            ReferenceTypes::PodcastEpisode,
+            ReferenceTypes::WorkingPaper,
+        ]
+    );
+    let types: Vec<ReferenceTypes> =
+        serde_yaml::from_str("[post-weblog, entry-dictionary, entry-encyclopedia]").unwrap();
+    assert_eq!(
+        types,
+        [
+            ReferenceTypes::Post,
+            ReferenceTypes::Entry,
+            ReferenceTypes::Entry,
+        ]
+    );
+}
+
+#[test]
+fn unknown_types() {
+    let types: Vec<ReferenceTypes> =
+        serde_yaml::from_str("[pamphlet, review, regulation]").unwrap();
+    assert_eq!(types, vec![ReferenceTypes::Other; 3]);
+    assert_eq!(ReferenceTypes::Other.csl_type(), "document");
+    assert_eq!(
+        ReferenceTypes::from_csl_type("document"),
+        Some(ReferenceTypes::Other)
+    );
+}
+
+#[test]
+fn csl_type_mapping() {
+    assert_eq!(ReferenceTypes::Article.csl_type(), "article-journal");
+    assert_eq!(ReferenceTypes::Preprint.csl_type(), "article");
+    assert_eq!(ReferenceTypes::PodcastEpisode.csl_type(), "broadcast");
+    assert_eq!(ReferenceTypes::WorkingPaper.csl_type(), "report");
+    assert_eq!(
+        ReferenceTypes::from_csl_type("paper-conference"),
+        Some(ReferenceTypes::ConferencePaper)
+    );
+    assert_eq!(
+        ReferenceTypes::from_csl_type("entry-encyclopedia"),
+        Some(ReferenceTypes::Entry)
+    );
+    assert_eq!(ReferenceTypes::from_csl_type("classic"), None);
+    for csl_type in [
+        "book",
+        "dataset",
+        "report",
+        "thesis",
+        "webpage",
+        "legal_case",
+    ] {
+        let reference_type = ReferenceTypes::from_csl_type(csl_type).unwrap();
+        assert_eq!(reference_type.csl_type(), csl_type);
+    }
+}
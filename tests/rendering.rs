@@ -0,0 +1,48 @@
+use csln_rs::bibliography::InputBibliography;
+use csln_rs::processor::Processor;
+use csln_rs::style::Style;
+
+const BIBLIOGRAPHY: &str = r#"
+doe: {title: A Study, author: [{name: "Jane Doe"}], issued: "2020"}
+"#;
+
+fn render(template: &str) -> String {
+    let style: Style =
+        serde_yaml::from_str(&format!("bibliography:\n  template:\n{}", template)).unwrap();
+    let bibliography: InputBibliography = serde_yaml::from_str(BIBLIOGRAPHY).unwrap();
+    Processor::new(&style, &bibliography)
+        .render_bibliography()
+        .remove(0)
+        .value
+}
+
+#[test]
+fn terms_and_text() {
+    let template = r#"
+    - term: in
+    - term: page
+      format: short
+    - text: Online
+"#;
+    assert_eq!(render(template), "in. p. Online.");
+}
+
+#[test]
+fn entry_and_template_delimiters() {
+    let template = r#"
+    - contributor: author
+    - template:
+        - title: title
+        - date: issued
+          format: year
+    - variable: doi
+    - text: End
+"#;
+    assert_eq!(render(template), "Jane Doe. A Study 2020. End.");
+}
+
+#[test]
+fn closing_period_is_not_doubled() {
+    assert_eq!(render("    - text: Done."), "Done.");
+    assert_eq!(render("    - text: Done?"), "Done?");
+}
@@ -0,0 +1,76 @@
+use csln_rs::bibliography::InputBibliography;
+use csln_rs::citation::Citation;
+use csln_rs::processor::Processor;
+use csln_rs::style::Style;
+
+const BIBLIOGRAPHY: &str = r#"
+may: {title: The Zebra, author: [{name: "Jane Doe"}], issued: "2020-05-17"}
+march: {title: A Yak, author: [{name: "Jane Doe"}], issued: "2020-03"}
+winter: {title: An Ox, author: [{name: "Al Roe"}], issued: "2020-24"}
+early: {title: Bee, author: [{name: "Al Roe"}], issued: "2019-12-31"}
+undated: {title: Ant, author: [{name: "Ed Moe"}]}
+anonymous: {title: Cat, issued: "2018"}
+"#;
+
+/// The keys of the references in bibliography order per the sort option, with the citations.
+fn sorted(sort: &str, citations: &[Citation]) -> Vec<String> {
+    let style: Style = serde_yaml::from_str(&format!("options:\n  sort: {}", sort)).unwrap();
+    let bibliography: InputBibliography = serde_yaml::from_str(BIBLIOGRAPHY).unwrap();
+    Processor::new(&style, &bibliography)
+        .with_citations(citations)
+        .sorted_ids()
+        .into_iter()
+        .cloned()
+        .collect()
+}
+
+#[test]
+fn years_sort_on_the_full_date() {
+    assert_eq!(
+        sorted("[{key: year}]", &[]),
+        ["anonymous", "early", "march", "may", "winter", "undated"]
+    );
+}
+
+#[test]
+fn descending_order_keeps_missing_values_last() {
+    assert_eq!(
+        sorted("[{key: year, order: descending}]", &[]),
+        ["winter", "may", "march", "early", "anonymous", "undated"]
+    );
+    assert_eq!(
+        sorted("[{key: author, order: descending}]", &[]),
+        ["early", "winter", "undated", "march", "may", "anonymous"]
+    );
+}
+
+#[test]
+fn titles_sort_without_leading_articles() {
+    assert_eq!(
+        sorted("[{key: title}]", &[]),
+        ["undated", "early", "anonymous", "winter", "march", "may"]
+    );
+}
+
+#[test]
+fn multiple_keys_sort_stably() {
+    assert_eq!(
+        sorted("[{key: author}, {key: year}]", &[]),
+        ["march", "may", "undated", "early", "winter", "anonymous"]
+    );
+    assert_eq!(
+        sorted("[{key: author}, {key: title, order: descending}]", &[]),
+        ["may", "march", "undated", "winter", "early", "anonymous"]
+    );
+}
+
+#[test]
+fn as_cited() {
+    let citations: Vec<Citation> =
+        serde_yaml::from_str("[{references: [{refID: winter}, {refID: may}]}, {references: [{refID: early}, {refID: winter}]}]")
+            .unwrap();
+    assert_eq!(
+        sorted("[{key: as-cited}]", &citations),
+        ["winter", "may", "early", "anonymous", "march", "undated"]
+    );
+}
@@ -0,0 +1,88 @@
+use csln_rs::bibliography::InputBibliography;
+use csln_rs::processor::Processor;
+use csln_rs::style::Style;
+
+const BIBLIOGRAPHY: &str = r#"
+authored: {title: Middle Book, author: [{name: "Mia Moe"}], editor: [{name: "Ed Poe"}]}
+edited: {title: Edited Book, editor: [{name: "Zoe Zed"}], translator: [{name: "Ann Ash"}]}
+translated: {title: Translated Book, translator: [{name: "Bo Bee"}]}
+untitled: {title: A Zoology}
+"#;
+
+/// The bibliography entries in order per the author substitutes.
+fn render(substitutes: &str) -> Vec<String> {
+    let style: Style = serde_yaml::from_str(&format!(
+        r#"
+options:
+  substitute:
+    author: {}
+  sort: [{{key: author}}]
+bibliography:
+  template:
+    - contributor: author
+    - title: title
+    - contributor: editor
+    - contributor: translator
+"#,
+        substitutes
+    ))
+    .unwrap();
+    let bibliography: InputBibliography = serde_yaml::from_str(BIBLIOGRAPHY).unwrap();
+    Processor::new(&style, &bibliography)
+        .render_bibliography()
+        .into_iter()
+        .map(|entry| entry.value)
+        .collect()
+}
+
+#[test]
+fn editor_substitute() {
+    assert_eq!(
+        render("[editor]"),
+        [
+            "Mia Moe. Middle Book. Ed Poe.",
+            "Zoe Zed. Edited Book. Ann Ash.",
+            "Translated Book. Bo Bee.",
+            "A Zoology."
+        ]
+    );
+}
+
+#[test]
+fn translator_substitute() {
+    assert_eq!(
+        render("[translator]"),
+        [
+            "Ann Ash. Edited Book. Zoe Zed.",
+            "Bo Bee. Translated Book.",
+            "Mia Moe. Middle Book. Ed Poe.",
+            "A Zoology."
+        ]
+    );
+}
+
+#[test]
+fn title_substitute() {
+    assert_eq!(
+        render("[title]"),
+        [
+            "Edited Book. Zoe Zed. Ann Ash.",
+            "Mia Moe. Middle Book. Ed Poe.",
+            "Translated Book. Bo Bee.",
+            "A Zoology."
+        ]
+    );
+}
+
+#[test]
+fn first_listed_substitute() {
+    assert_eq!(
+        render("[editor, translator, title]"),
+        [
+            "Bo Bee. Translated Book.",
+            "Mia Moe. Middle Book. Ed Poe.",
+            "Zoe Zed. Edited Book. Ann Ash.",
+            "A Zoology."
+        ]
+    );
+}
@@ -0,0 +1,135 @@
+use csln_rs::bibliography::InputBibliography;
+use csln_rs::processor::{flatten_style, resolve_templates, Processor, TemplateError};
+use csln_rs::style::Style;
+
+const BIBLIOGRAPHY: &str = r#"
+book: {type: book, title: A Book, author: [{name: "Jane Doe"}], issued: "2020"}
+film: {type: film, title: A Film, director: [{name: "Ed Poe"}], issued: "2021"}
+"#;
+
+fn style(yaml: &str) -> Style {
+    serde_yaml::from_str(yaml).unwrap()
+}
+
+/// The entries of the bibliography per the flattened style.
+fn render(style: &Style) -> Vec<String> {
+    let style = flatten_style(style).unwrap();
+    let bibliography: InputBibliography = serde_yaml::from_str(BIBLIOGRAPHY).unwrap();
+    let mut entries: Vec<String> = Processor::new(&style, &bibliography)
+        .render_bibliography()
+        .into_iter()
+        .map(|entry| entry.value)
+        .collect();
+    entries.sort();
+    entries
+}
+
+#[test]
+fn templates_resolve_recursively() {
+    let style = style(
+        r#"
+templates:
+  entry:
+    - templateKey: author-date
+    - title: title
+  author-date:
+    - contributor: author
+    - templateKey: year
+  year:
+    - date: issued
+      format: year
+bibliography:
+  template:
+    - templateKey: entry
+"#,
+    );
+    let templates = resolve_templates(&style).unwrap();
+    assert_eq!(templates.len(), 3);
+    assert!(templates
+        .values()
+        .flatten()
+        .all(|component| component.template_key.is_none()));
+    assert_eq!(render(&style), ["2021 A Film.", "Jane Doe 2020 A Book."]);
+}
+
+#[test]
+fn conditional_templates_resolve() {
+    let style = style(
+        r#"
+templates:
+  book: [{title: title}, {text: book}]
+  other: [{title: title}, {date: issued, format: year}]
+bibliography:
+  template:
+    - when:
+        - isRefType: [book]
+          templateKey: book
+      else:
+        templateKey: other
+"#,
+    );
+    let flat = flatten_style(&style).unwrap();
+    let component = &flat
+        .bibliography
+        .as_ref()
+        .unwrap()
+        .template
+        .as_ref()
+        .unwrap()[0];
+    assert!(component.when.as_ref().unwrap()[0].template_key.is_none());
+    assert!(component
+        .template_component_else
+        .as_ref()
+        .unwrap()
+        .template_key
+        .is_none());
+    assert_eq!(render(&style), ["A Book book.", "A Film 2021."]);
+}
+
+#[test]
+fn cycles_are_errors() {
+    let style = style(
+        r#"
+templates:
+  a: [{templateKey: b}]
+  b: [{title: title}, {templateKey: c}]
+  c: [{templateKey: a}]
+"#,
+    );
+    let error = resolve_templates(&style).unwrap_err();
+    assert_eq!(
+        error,
+        TemplateError::Cycle(vec![
+            "a".to_string(),
+            "b".to_string(),
+            "c".to_string(),
+            "a".to_string()
+        ])
+    );
+    assert_eq!(
+        error.to_string(),
+        "templates refer to each other in a cycle: a -> b -> c -> a"
+    );
+}
+
+#[test]
+fn missing_keys_are_errors() {
+    let style = style(
+        r#"
+templates:
+  entry: [{templateKey: undefined}]
+bibliography:
+  template:
+    - templateKey: entry
+"#,
+    );
+    assert_eq!(
+        resolve_templates(&style).unwrap_err(),
+        TemplateError::Missing("undefined".to_string())
+    );
+    let style = self::style("citation:\n  template:\n    - templateKey: undefined");
+    assert_eq!(
+        flatten_style(&style).unwrap_err().to_string(),
+        "template \"undefined\" is not defined in the style templates"
+    );
+}
@@ -0,0 +1,108 @@
+use csln_rs::bibliography::InputBibliography;
+use csln_rs::citation::Citation;
+use csln_rs::locale::Locales;
+use csln_rs::processor::Processor;
+use csln_rs::style::Style;
+
+const BIBLIOGRAPHY: &str = r#"
+a:
+  title: {main: "The Origin of Species", sub: ["A Study", "Second Part"], short: Origin}
+b:
+  title: "Roe v. Wade: a decision of the supreme court"
+"#;
+
+fn render(template: &str, tag: &str) -> Vec<String> {
+    let style: Style =
+        serde_yaml::from_str(&format!("bibliography:\n  template:\n{}", template)).unwrap();
+    let bibliography: InputBibliography = serde_yaml::from_str(BIBLIOGRAPHY).unwrap();
+    let locales = Locales::new();
+    let mut entries: Vec<String> = Processor::new(&style, &bibliography)
+        .with_locales(&locales, tag)
+        .render_bibliography()
+        .into_iter()
+        .map(|entry| entry.value)
+        .collect();
+    entries.sort();
+    entries
+}
+
+#[test]
+fn structured_and_split_titles() {
+    let template = r#"
+    - title: title
+    - title: title
+      format: main
+    - title: title
+      format: sub
+    - title: title
+      format: short
+"#;
+    assert_eq!(
+        render(template, "en-US"),
+        [
+            "Roe v. Wade: a decision of the supreme court. Roe v. Wade. a decision of the supreme court. Roe v. Wade.",
+            "The Origin of Species: A Study: Second Part. The Origin of Species. A Study: Second Part. Origin."
+        ]
+    );
+}
+
+#[test]
+fn subtitle_delimiter_of_locale() {
+    assert_eq!(
+        render("    - title: title", "fr-FR")[1],
+        "The Origin of Species\u{a0}: A Study\u{a0}: Second Part."
+    );
+}
+
+#[test]
+fn text_case() {
+    let template = r#"
+    - title: title
+      textCase: title
+"#;
+    assert_eq!(
+        render(template, "en-US"),
+        [
+            "Roe v. Wade: A Decision of the Supreme Court.",
+            "The Origin of Species: A Study: Second Part."
+        ]
+    );
+    assert_eq!(
+        render(template, "de-DE")[0],
+        "Roe v. Wade: a decision of the supreme court."
+    );
+    let template = r#"
+    - title: title
+      textCase: sentence
+    - title: title
+      format: short
+      textCase: uppercase
+"#;
+    assert_eq!(
+        render(template, "en-US")[1],
+        "The origin of species: A study: Second part. ORIGIN."
+    );
+}
+
+#[test]
+fn short_title_citations() {
+    let style: Style = serde_yaml::from_str(
+        r#"
+options:
+  substitute:
+    author: [title]
+citation:
+  template:
+    - contributor: author
+      format: short
+"#,
+    )
+    .unwrap();
+    let bibliography: InputBibliography = serde_yaml::from_str(BIBLIOGRAPHY).unwrap();
+    let citation: Citation = serde_yaml::from_str("references: [{refID: a}, {refID: b}]").unwrap();
+    let processor = Processor::new(&style, &bibliography);
+    assert_eq!(
+        processor.render_citation(&citation).unwrap(),
+        "(Origin; Roe v. Wade)"
+    );
+}
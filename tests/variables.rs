@@ -0,0 +1,77 @@
+use csln_rs::bibliography::InputBibliography;
+use csln_rs::processor::Processor;
+use csln_rs::style::Style;
+
+const BIBLIOGRAPHY: &str = r#"
+article:
+  type: article
+  title: A Study
+  author: [{name: "Jane Doe"}]
+  issued: "2020"
+  container-title: Journal of Things
+  volume: 12
+  issue: "3"
+  pages: "45-67"
+  DOI: "10.1000/xyz"
+film:
+  title: Film
+  director: [{name: "Ed Poe"}]
+  issued: "2021"
+  publisher-place: Berlin
+  edition: 2
+"#;
+
+fn render(template: &str) -> Vec<String> {
+    let style: Style =
+        serde_yaml::from_str(&format!("bibliography:\n  template:\n{}", template)).unwrap();
+    let bibliography: InputBibliography = serde_yaml::from_str(BIBLIOGRAPHY).unwrap();
+    Processor::new(&style, &bibliography)
+        .render_bibliography()
+        .into_iter()
+        .map(|entry| entry.value)
+        .collect()
+}
+
+#[test]
+fn journal_article_variables() {
+    let entries = render(
+        r#"
+    - title: container-title
+    - template:
+        - variable: volume
+        - variable: issue
+          wrap: parentheses
+    - variable: pages
+    - variable: doi
+"#,
+    );
+    assert_eq!(entries[0], "Journal of Things. 12 (3). 45–67. 10.1000/xyz.");
+}
+
+#[test]
+fn contributor_roles() {
+    let entries = render(
+        r#"
+    - contributor: director
+      options: {contributors: {role: {form: short, omit: []}}}
+    - title: title
+"#,
+    );
+    assert_eq!(entries[0], "A Study.");
+    assert_eq!(entries[1], "Ed Poe (dir.). Film.");
+}
+
+#[test]
+fn has_variable() {
+    let entries = render(
+        r#"
+    - title: title
+    - when:
+        - hasVariable: [publisher-place, edition]
+          template: [{variable: publisher-place}, {variable: edition}]
+      else:
+        template: [{text: none}]
+"#,
+    );
+    assert_eq!(entries, ["A Study. none.", "Film. Berlin 2."]);
+}